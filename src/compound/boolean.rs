@@ -0,0 +1,167 @@
+use crate::{options::MinimumShouldMatch, QueryClause};
+use serde::Serialize;
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct Bool {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    must: Vec<QueryClause>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    must_not: Vec<QueryClause>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    should: Vec<QueryClause>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    filter: Vec<QueryClause>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    minimum_should_match: Option<MinimumShouldMatch>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    boost: Option<f64>,
+}
+
+impl Bool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a clause to the must field.
+    pub fn must<T: Into<QueryClause>>(self, query: T) -> Self {
+        let mut must = self.must;
+        must.push(query.into());
+        Self { must, ..self }
+    }
+
+    /// Adds a clause to the must_not field.
+    pub fn must_not<T: Into<QueryClause>>(self, query: T) -> Self {
+        let mut must_not = self.must_not;
+        must_not.push(query.into());
+        Self { must_not, ..self }
+    }
+
+    /// Adds a clause to the should field.
+    pub fn should<T: Into<QueryClause>>(self, query: T) -> Self {
+        let mut should = self.should;
+        should.push(query.into());
+        Self { should, ..self }
+    }
+
+    /// Adds a clause to the filter field.
+    pub fn filter<T: Into<QueryClause>>(self, query: T) -> Self {
+        let mut filter = self.filter;
+        filter.push(query.into());
+        Self { filter, ..self }
+    }
+
+    /// Sets minimum_should_match field, a count like `1` or `-2`, or a percentage or
+    /// combination like `75%` or `3<90%`.
+    pub fn minimum_should_match<T: Into<MinimumShouldMatch>>(
+        self,
+        minimum_should_match: T,
+    ) -> Self {
+        Self {
+            minimum_should_match: Some(minimum_should_match.into()),
+            ..self
+        }
+    }
+
+    pub fn boost<T: Into<f64>>(self, boost: T) -> Self {
+        Self {
+            boost: Some(boost.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::full_text::{Match, MatchPhrase};
+    use crate::term_level::{Range, Term};
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = Bool::new()
+            .must(Match::new().field("text_entry").value("love"))
+            .must_not(
+                MatchPhrase::new()
+                    .field("text_entry")
+                    .value("long live the king"),
+            )
+            .should(Term::new().field("speaker").value("ROMEO"))
+            .filter(Range::new().field("line_id").gte(10))
+            .minimum_should_match(1_u64)
+            .boost(1.5);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "must": [
+                { "match": { "text_entry": { "query": "love" } } }
+            ],
+            "must_not": [
+                { "match_phrase": { "text_entry": { "query": "long live the king" } } }
+            ],
+            "should": [
+                { "term": { "speaker": { "value": "ROMEO" } } }
+            ],
+            "filter": [
+                { "range": { "line_id": { "gte": 10 } } }
+            ],
+            "minimum_should_match": 1,
+            "boost": 1.5
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_nested_bool_to_json() {
+        let query = Bool::new().should(
+            Bool::new()
+                .must(Term::new().field("speaker").value("JULIET"))
+                .filter(Term::new().field("play_name").value("Romeo and Juliet")),
+        );
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "should": [
+                {
+                    "bool": {
+                        "must": [
+                            { "term": { "speaker": { "value": "JULIET" } } }
+                        ],
+                        "filter": [
+                            { "term": { "play_name": { "value": "Romeo and Juliet" } } }
+                        ]
+                    }
+                }
+            ]
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_percentage_minimum_should_match_to_json() {
+        let query = Bool::new()
+            .should(Term::new().field("speaker").value("ROMEO"))
+            .should(Term::new().field("speaker").value("JULIET"))
+            .minimum_should_match("75%");
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "should": [
+                { "term": { "speaker": { "value": "ROMEO" } } },
+                { "term": { "speaker": { "value": "JULIET" } } }
+            ],
+            "minimum_should_match": "75%"
+        });
+
+        assert_eq!(json, expected);
+    }
+}
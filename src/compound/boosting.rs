@@ -0,0 +1,69 @@
+use crate::QueryClause;
+use serde::Serialize;
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct Boosting {
+    positive: Option<QueryClause>,
+    negative: Option<QueryClause>,
+    negative_boost: Option<f64>,
+}
+
+impl Boosting {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets positive field.
+    pub fn positive<T: Into<QueryClause>>(self, query: T) -> Self {
+        Self {
+            positive: Some(query.into()),
+            ..self
+        }
+    }
+
+    /// Sets negative field.
+    pub fn negative<T: Into<QueryClause>>(self, query: T) -> Self {
+        Self {
+            negative: Some(query.into()),
+            ..self
+        }
+    }
+
+    /// Sets negative_boost field, a factor between 0 and 1 applied to the relevance score of
+    /// documents matching the negative query.
+    pub fn negative_boost<T: Into<f64>>(self, negative_boost: T) -> Self {
+        Self {
+            negative_boost: Some(negative_boost.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::full_text::Match;
+    use crate::term_level::Term;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = Boosting::new()
+            .positive(Match::new().field("text_entry").value("shall"))
+            .negative(Term::new().field("text_entry").value("friend"))
+            .negative_boost(0.1);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "positive": {
+                "match": { "text_entry": { "query": "shall" } }
+            },
+            "negative": {
+                "term": { "text_entry": { "value": "friend" } }
+            },
+            "negative_boost": 0.1
+        });
+
+        assert_eq!(json, expected);
+    }
+}
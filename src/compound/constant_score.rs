@@ -0,0 +1,61 @@
+use crate::QueryClause;
+use serde::Serialize;
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct ConstantScore {
+    filter: Option<QueryClause>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    boost: Option<f64>,
+}
+
+impl ConstantScore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets filter field.
+    pub fn filter<T: Into<QueryClause>>(self, query: T) -> Self {
+        Self {
+            filter: Some(query.into()),
+            ..self
+        }
+    }
+
+    pub fn boost<T: Into<f64>>(self, boost: T) -> Self {
+        Self {
+            boost: Some(boost.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::term_level::Terms;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = ConstantScore::new()
+            .filter(
+                Terms::new()
+                    .field("play_name")
+                    .values(vec!["Hamlet", "Macbeth"]),
+            )
+            .boost(1.2);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "filter": {
+                "terms": {
+                    "play_name": ["Hamlet", "Macbeth"]
+                }
+            },
+            "boost": 1.2
+        });
+
+        assert_eq!(json, expected);
+    }
+}
@@ -0,0 +1,102 @@
+use crate::QueryClause;
+use serde::Serialize;
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct DisMax {
+    queries: Vec<QueryClause>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tie_breaker: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    boost: Option<f64>,
+}
+
+impl DisMax {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn queries<Q, T>(self, queries: Q) -> Self
+    where
+        Q: IntoIterator<Item = T>,
+        T: Into<QueryClause>,
+    {
+        Self {
+            queries: queries.into_iter().map(|q| q.into()).collect(),
+            ..self
+        }
+    }
+
+    pub fn query<T: Into<QueryClause>>(self, query: T) -> Self {
+        let mut queries = self.queries;
+        queries.push(query.into());
+        Self { queries, ..self }
+    }
+
+    pub fn tie_breaker<T: Into<f64>>(self, tie_breaker: T) -> Self {
+        Self {
+            tie_breaker: Some(tie_breaker.into()),
+            ..self
+        }
+    }
+
+    pub fn boost<T: Into<f64>>(self, boost: T) -> Self {
+        Self {
+            boost: Some(boost.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::full_text::{Match, MatchPhrase};
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = DisMax::new()
+            .query(
+                Match::new()
+                    .field("title")
+                    .value("Shakespeare poems")
+                    .analyzer("english"),
+            )
+            .query(
+                MatchPhrase::new()
+                    .field("body")
+                    .value("Shakespeare poems")
+                    .slop(2_u64),
+            )
+            .tie_breaker(0.7)
+            .boost(1.2);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "queries": [
+                {
+                    "match": {
+                        "title": {
+                            "query": "Shakespeare poems",
+                            "analyzer": "english"
+                        }
+                    }
+                },
+                {
+                    "match_phrase": {
+                        "body": {
+                            "query": "Shakespeare poems",
+                            "slop": 2
+                        }
+                    }
+                }
+            ],
+            "tie_breaker": 0.7,
+            "boost": 1.2
+        });
+
+        assert_eq!(json, expected);
+    }
+}
@@ -0,0 +1,506 @@
+use crate::options::{
+    BoostMode, FieldValueFactorModifier, FunctionScoreMode, MultiValueMode, Script,
+};
+use crate::QueryClause;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::Value;
+
+#[derive(Debug, Default, Clone, serde::Serialize)]
+pub struct FunctionScore {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query: Option<QueryClause>,
+
+    functions: Vec<ScoreFunction>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    score_mode: Option<FunctionScoreMode>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    boost_mode: Option<BoostMode>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_boost: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_score: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    boost: Option<f64>,
+}
+
+impl FunctionScore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn query<T: Into<QueryClause>>(self, query: T) -> Self {
+        Self {
+            query: Some(query.into()),
+            ..self
+        }
+    }
+
+    pub fn functions<F, T>(self, functions: F) -> Self
+    where
+        F: IntoIterator<Item = T>,
+        T: Into<ScoreFunction>,
+    {
+        Self {
+            functions: functions.into_iter().map(|f| f.into()).collect(),
+            ..self
+        }
+    }
+
+    pub fn function<T: Into<ScoreFunction>>(self, function: T) -> Self {
+        let mut functions = self.functions;
+        functions.push(function.into());
+        Self { functions, ..self }
+    }
+
+    pub fn score_mode<T: Into<FunctionScoreMode>>(self, score_mode: T) -> Self {
+        Self {
+            score_mode: Some(score_mode.into()),
+            ..self
+        }
+    }
+
+    pub fn boost_mode<T: Into<BoostMode>>(self, boost_mode: T) -> Self {
+        Self {
+            boost_mode: Some(boost_mode.into()),
+            ..self
+        }
+    }
+
+    pub fn max_boost<T: Into<f64>>(self, max_boost: T) -> Self {
+        Self {
+            max_boost: Some(max_boost.into()),
+            ..self
+        }
+    }
+
+    pub fn min_score<T: Into<f64>>(self, min_score: T) -> Self {
+        Self {
+            min_score: Some(min_score.into()),
+            ..self
+        }
+    }
+
+    pub fn boost<T: Into<f64>>(self, boost: T) -> Self {
+        Self {
+            boost: Some(boost.into()),
+            ..self
+        }
+    }
+}
+
+/// An entry of the functions field of a function_score query.
+#[derive(Debug, Default, Clone, serde::Serialize)]
+pub struct ScoreFunction {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<QueryClause>,
+
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    function: Option<Function>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    weight: Option<f64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Function {
+    RandomScore(RandomScore),
+    FieldValueFactor(FieldValueFactor),
+    ScriptScore(ScriptScore),
+    Gauss(Decay),
+    Exp(Decay),
+    Linear(Decay),
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+struct ScriptScore {
+    script: Script,
+}
+
+impl ScoreFunction {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets filter field. The function is applied only to documents matching it.
+    pub fn filter<T: Into<QueryClause>>(self, query: T) -> Self {
+        Self {
+            filter: Some(query.into()),
+            ..self
+        }
+    }
+
+    pub fn weight<T: Into<f64>>(self, weight: T) -> Self {
+        Self {
+            weight: Some(weight.into()),
+            ..self
+        }
+    }
+
+    pub fn random_score<T: Into<RandomScore>>(self, random_score: T) -> Self {
+        Self {
+            function: Some(Function::RandomScore(random_score.into())),
+            ..self
+        }
+    }
+
+    pub fn field_value_factor<T: Into<FieldValueFactor>>(self, field_value_factor: T) -> Self {
+        Self {
+            function: Some(Function::FieldValueFactor(field_value_factor.into())),
+            ..self
+        }
+    }
+
+    pub fn script_score<T: Into<Script>>(self, script: T) -> Self {
+        let script_score = ScriptScore {
+            script: script.into(),
+        };
+        Self {
+            function: Some(Function::ScriptScore(script_score)),
+            ..self
+        }
+    }
+
+    pub fn gauss<T: Into<Decay>>(self, decay: T) -> Self {
+        Self {
+            function: Some(Function::Gauss(decay.into())),
+            ..self
+        }
+    }
+
+    pub fn exp<T: Into<Decay>>(self, decay: T) -> Self {
+        Self {
+            function: Some(Function::Exp(decay.into())),
+            ..self
+        }
+    }
+
+    pub fn linear<T: Into<Decay>>(self, decay: T) -> Self {
+        Self {
+            function: Some(Function::Linear(decay.into())),
+            ..self
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize)]
+pub struct RandomScore {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    field: Option<String>,
+}
+
+impl RandomScore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn seed<T: Into<Value>>(self, seed: T) -> Self {
+        Self {
+            seed: Some(seed.into()),
+            ..self
+        }
+    }
+
+    pub fn field<T: Into<String>>(self, field: T) -> Self {
+        Self {
+            field: Some(field.into()),
+            ..self
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize)]
+pub struct FieldValueFactor {
+    field: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    factor: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    modifier: Option<FieldValueFactorModifier>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    missing: Option<f64>,
+}
+
+impl FieldValueFactor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field<T: Into<String>>(self, field: T) -> Self {
+        Self {
+            field: Some(field.into()),
+            ..self
+        }
+    }
+
+    pub fn factor<T: Into<f64>>(self, factor: T) -> Self {
+        Self {
+            factor: Some(factor.into()),
+            ..self
+        }
+    }
+
+    pub fn modifier<T: Into<FieldValueFactorModifier>>(self, modifier: T) -> Self {
+        Self {
+            modifier: Some(modifier.into()),
+            ..self
+        }
+    }
+
+    pub fn missing<T: Into<f64>>(self, missing: T) -> Self {
+        Self {
+            missing: Some(missing.into()),
+            ..self
+        }
+    }
+}
+
+/// Parameters of the gauss, exp and linear decay functions.
+#[derive(Debug, Default, Clone)]
+pub struct Decay {
+    field: Option<String>,
+    value: DecayValues,
+    multi_value_mode: Option<MultiValueMode>,
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize)]
+struct DecayValues {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    origin: Option<Value>,
+
+    scale: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offset: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    decay: Option<f64>,
+}
+
+impl Decay {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field<T: Into<String>>(self, field: T) -> Self {
+        Self {
+            field: Some(field.into()),
+            ..self
+        }
+    }
+
+    /// Sets origin field. It may be omitted for date fields, where it defaults to `now`.
+    pub fn origin<T: Into<Value>>(self, origin: T) -> Self {
+        let value = DecayValues {
+            origin: Some(origin.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn scale<T: Into<Value>>(self, scale: T) -> Self {
+        let value = DecayValues {
+            scale: Some(scale.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn offset<T: Into<Value>>(self, offset: T) -> Self {
+        let value = DecayValues {
+            offset: Some(offset.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn decay<T: Into<f64>>(self, decay: T) -> Self {
+        let value = DecayValues {
+            decay: Some(decay.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn multi_value_mode<T: Into<MultiValueMode>>(self, multi_value_mode: T) -> Self {
+        Self {
+            multi_value_mode: Some(multi_value_mode.into()),
+            ..self
+        }
+    }
+}
+
+impl Serialize for Decay {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(None)?;
+        state.serialize_entry(self.field.as_deref().unwrap_or_default(), &self.value)?;
+        if let Some(multi_value_mode) = self.multi_value_mode.as_ref() {
+            state.serialize_entry("multi_value_mode", multi_value_mode)?;
+        }
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::full_text::Match;
+    use crate::term_level::Term;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = FunctionScore::new()
+            .query(Match::new().field("text_entry").value("love"))
+            .function(
+                ScoreFunction::new()
+                    .filter(Term::new().field("speaker").value("ROMEO"))
+                    .weight(2),
+            )
+            .function(
+                ScoreFunction::new()
+                    .random_score(RandomScore::new().seed(10).field("_seq_no"))
+                    .weight(0.5),
+            )
+            .function(
+                ScoreFunction::new().field_value_factor(
+                    FieldValueFactor::new()
+                        .field("likes")
+                        .factor(1.2)
+                        .modifier(FieldValueFactorModifier::Sqrt)
+                        .missing(1),
+                ),
+            )
+            .function(ScoreFunction::new().script_score("_score * doc['likes'].value"))
+            .score_mode(FunctionScoreMode::Sum)
+            .boost_mode(BoostMode::Multiply)
+            .max_boost(42)
+            .min_score(1)
+            .boost(5);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "query": {
+                "match": { "text_entry": { "query": "love" } }
+            },
+            "functions": [
+                {
+                    "filter": { "term": { "speaker": { "value": "ROMEO" } } },
+                    "weight": 2.0
+                },
+                {
+                    "random_score": { "seed": 10, "field": "_seq_no" },
+                    "weight": 0.5
+                },
+                {
+                    "field_value_factor": {
+                        "field": "likes",
+                        "factor": 1.2,
+                        "modifier": "sqrt",
+                        "missing": 1.0
+                    }
+                },
+                {
+                    "script_score": {
+                        "script": { "source": "_score * doc['likes'].value" }
+                    }
+                }
+            ],
+            "score_mode": "sum",
+            "boost_mode": "multiply",
+            "max_boost": 42.0,
+            "min_score": 1.0,
+            "boost": 5.0
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_decay_functions_to_json() {
+        let query = FunctionScore::new()
+            .function(
+                ScoreFunction::new().gauss(
+                    Decay::new()
+                        .field("date")
+                        .origin("2024-01-01")
+                        .scale("10d")
+                        .offset("5d")
+                        .decay(0.5)
+                        .multi_value_mode(MultiValueMode::Avg),
+                ),
+            )
+            .function(
+                ScoreFunction::new()
+                    .filter(Term::new().field("speaker").value("ROMEO"))
+                    .exp(Decay::new().field("line_id").origin(100).scale(10)),
+            )
+            .function(
+                ScoreFunction::new().linear(
+                    Decay::new()
+                        .field("location")
+                        .origin("40.7,-74.0")
+                        .scale("2km"),
+                ),
+            );
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "functions": [
+                {
+                    "gauss": {
+                        "date": {
+                            "origin": "2024-01-01",
+                            "scale": "10d",
+                            "offset": "5d",
+                            "decay": 0.5
+                        },
+                        "multi_value_mode": "avg"
+                    }
+                },
+                {
+                    "filter": { "term": { "speaker": { "value": "ROMEO" } } },
+                    "exp": {
+                        "line_id": { "origin": 100, "scale": 10 }
+                    }
+                },
+                {
+                    "linear": {
+                        "location": { "origin": "40.7,-74.0", "scale": "2km" }
+                    }
+                }
+            ]
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_omits_decay_origin_when_not_set() {
+        let function = ScoreFunction::new().gauss(Decay::new().field("date").scale("10d"));
+
+        let json = serde_json::to_value(function).unwrap();
+
+        let expected = serde_json::json!({
+            "gauss": {
+                "date": { "scale": "10d" }
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::QueryClause;
+use serde::Serialize;
+
+/// Combines the relevance scores of its sub-queries. It requires a search pipeline
+/// with a normalization processor, see [`SearchPipeline`](crate::pipeline::SearchPipeline).
+///
+/// OpenSearch accepts it only as the top-level query, so it is built with
+/// [`Query::hybrid`](crate::Query::hybrid) and can't be nested in other queries.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct Hybrid {
+    queries: Vec<QueryClause>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pagination_depth: Option<u64>,
+}
+
+impl Hybrid {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn queries<Q, T>(self, queries: Q) -> Self
+    where
+        Q: IntoIterator<Item = T>,
+        T: Into<QueryClause>,
+    {
+        Self {
+            queries: queries.into_iter().map(|q| q.into()).collect(),
+            ..self
+        }
+    }
+
+    pub fn query<T: Into<QueryClause>>(self, query: T) -> Self {
+        let mut queries = self.queries;
+        queries.push(query.into());
+        Self { queries, ..self }
+    }
+
+    pub fn pagination_depth<T: Into<u64>>(self, pagination_depth: T) -> Self {
+        Self {
+            pagination_depth: Some(pagination_depth.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::full_text::{Match, Neural};
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = Hybrid::new()
+            .query(Match::new().field("text").value("cowboy rodeo bronco"))
+            .query(
+                Neural::new()
+                    .field("passage_embedding")
+                    .query_text("wild west")
+                    .model_id("aVeif4oB5Vm0Tdw8zYO2")
+                    .k(5_u64),
+            )
+            .pagination_depth(10_u64);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "queries": [
+                {
+                    "match": {
+                        "text": {
+                            "query": "cowboy rodeo bronco"
+                        }
+                    }
+                },
+                {
+                    "neural": {
+                        "passage_embedding": {
+                            "query_text": "wild west",
+                            "model_id": "aVeif4oB5Vm0Tdw8zYO2",
+                            "k": 5
+                        }
+                    }
+                }
+            ],
+            "pagination_depth": 10
+        });
+
+        assert_eq!(json, expected);
+    }
+}
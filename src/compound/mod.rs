@@ -0,0 +1,45 @@
+use serde::Serialize;
+
+mod boolean;
+mod boosting;
+mod constant_score;
+mod dis_max;
+mod function_score;
+mod hybrid;
+
+pub use boolean::Bool;
+pub use boosting::Boosting;
+pub use constant_score::ConstantScore;
+pub use dis_max::DisMax;
+pub use function_score::{Decay, FieldValueFactor, FunctionScore, RandomScore, ScoreFunction};
+pub use hybrid::Hybrid;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Compound {
+    Bool(Box<Bool>),
+    Boosting(Box<Boosting>),
+    ConstantScore(Box<ConstantScore>),
+    DisMax(Box<DisMax>),
+    FunctionScore(Box<FunctionScore>),
+}
+
+macro_rules! from_types {
+    ($($ty:ident),*) => {
+        $(
+            impl From<$ty> for Compound {
+                fn from(val: $ty) -> Self {
+                    Self::$ty(Box::new(val.into()))
+                }
+            }
+        )*
+    }
+}
+
+from_types! {
+    Bool,
+    Boosting,
+    ConstantScore,
+    DisMax,
+    FunctionScore
+}
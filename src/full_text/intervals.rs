@@ -0,0 +1,603 @@
+use crate::options::{Fuzziness, Script};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+#[derive(Debug, Default, Clone)]
+pub struct Intervals {
+    field: Option<String>,
+    rule: Option<IntervalsRule>,
+}
+
+impl Intervals {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field<T: Into<String>>(self, field: T) -> Self {
+        Self {
+            field: Some(field.into()),
+            ..self
+        }
+    }
+
+    pub fn rule<T: Into<IntervalsRule>>(self, rule: T) -> Self {
+        Self {
+            rule: Some(rule.into()),
+            ..self
+        }
+    }
+}
+
+impl Serialize for Intervals {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(1))?;
+        state.serialize_entry(self.field.as_deref().unwrap_or_default(), &self.rule)?;
+        state.end()
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntervalsRule {
+    Match(Box<IntervalsMatch>),
+    Prefix(Box<IntervalsPrefix>),
+    Wildcard(Box<IntervalsWildcard>),
+    Fuzzy(Box<IntervalsFuzzy>),
+    AllOf(Box<IntervalsAllOf>),
+    AnyOf(Box<IntervalsAnyOf>),
+}
+
+macro_rules! from_types {
+    ($($variant:ident: $ty:ident),*) => {
+        $(
+            impl From<$ty> for IntervalsRule {
+                fn from(val: $ty) -> Self {
+                    Self::$variant(Box::new(val))
+                }
+            }
+        )*
+    }
+}
+
+from_types! {
+    Match: IntervalsMatch,
+    Prefix: IntervalsPrefix,
+    Wildcard: IntervalsWildcard,
+    Fuzzy: IntervalsFuzzy,
+    AllOf: IntervalsAllOf,
+    AnyOf: IntervalsAnyOf
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize)]
+pub struct IntervalsMatch {
+    query: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_gaps: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ordered: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    use_field: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<IntervalsFilter>,
+}
+
+impl IntervalsMatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn query<T: Into<String>>(self, query: T) -> Self {
+        Self {
+            query: Some(query.into()),
+            ..self
+        }
+    }
+
+    /// Sets max_gaps field. `-1` allows any number of gaps.
+    pub fn max_gaps<T: Into<i64>>(self, max_gaps: T) -> Self {
+        Self {
+            max_gaps: Some(max_gaps.into()),
+            ..self
+        }
+    }
+
+    pub fn ordered(self, ordered: bool) -> Self {
+        Self {
+            ordered: Some(ordered),
+            ..self
+        }
+    }
+
+    pub fn analyzer<T: Into<String>>(self, analyzer: T) -> Self {
+        Self {
+            analyzer: Some(analyzer.into()),
+            ..self
+        }
+    }
+
+    pub fn use_field<T: Into<String>>(self, use_field: T) -> Self {
+        Self {
+            use_field: Some(use_field.into()),
+            ..self
+        }
+    }
+
+    pub fn filter<T: Into<IntervalsFilter>>(self, filter: T) -> Self {
+        Self {
+            filter: Some(filter.into()),
+            ..self
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize)]
+pub struct IntervalsPrefix {
+    prefix: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    use_field: Option<String>,
+}
+
+impl IntervalsPrefix {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn prefix<T: Into<String>>(self, prefix: T) -> Self {
+        Self {
+            prefix: Some(prefix.into()),
+            ..self
+        }
+    }
+
+    pub fn analyzer<T: Into<String>>(self, analyzer: T) -> Self {
+        Self {
+            analyzer: Some(analyzer.into()),
+            ..self
+        }
+    }
+
+    pub fn use_field<T: Into<String>>(self, use_field: T) -> Self {
+        Self {
+            use_field: Some(use_field.into()),
+            ..self
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize)]
+pub struct IntervalsWildcard {
+    pattern: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    use_field: Option<String>,
+}
+
+impl IntervalsWildcard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn pattern<T: Into<String>>(self, pattern: T) -> Self {
+        Self {
+            pattern: Some(pattern.into()),
+            ..self
+        }
+    }
+
+    pub fn analyzer<T: Into<String>>(self, analyzer: T) -> Self {
+        Self {
+            analyzer: Some(analyzer.into()),
+            ..self
+        }
+    }
+
+    pub fn use_field<T: Into<String>>(self, use_field: T) -> Self {
+        Self {
+            use_field: Some(use_field.into()),
+            ..self
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize)]
+pub struct IntervalsFuzzy {
+    term: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fuzziness: Option<Fuzziness>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prefix_length: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transpositions: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    use_field: Option<String>,
+}
+
+impl IntervalsFuzzy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn term<T: Into<String>>(self, term: T) -> Self {
+        Self {
+            term: Some(term.into()),
+            ..self
+        }
+    }
+
+    pub fn fuzziness<T: Into<Fuzziness>>(self, fuzziness: T) -> Self {
+        Self {
+            fuzziness: Some(fuzziness.into()),
+            ..self
+        }
+    }
+
+    pub fn prefix_length<T: Into<u64>>(self, prefix_length: T) -> Self {
+        Self {
+            prefix_length: Some(prefix_length.into()),
+            ..self
+        }
+    }
+
+    pub fn transpositions(self, transpositions: bool) -> Self {
+        Self {
+            transpositions: Some(transpositions),
+            ..self
+        }
+    }
+
+    pub fn analyzer<T: Into<String>>(self, analyzer: T) -> Self {
+        Self {
+            analyzer: Some(analyzer.into()),
+            ..self
+        }
+    }
+
+    pub fn use_field<T: Into<String>>(self, use_field: T) -> Self {
+        Self {
+            use_field: Some(use_field.into()),
+            ..self
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize)]
+pub struct IntervalsAllOf {
+    intervals: Vec<IntervalsRule>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_gaps: Option<i64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ordered: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<IntervalsFilter>,
+}
+
+impl IntervalsAllOf {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intervals<I, T>(self, intervals: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<IntervalsRule>,
+    {
+        Self {
+            intervals: intervals.into_iter().map(|i| i.into()).collect(),
+            ..self
+        }
+    }
+
+    pub fn interval<T: Into<IntervalsRule>>(self, interval: T) -> Self {
+        let mut intervals = self.intervals;
+        intervals.push(interval.into());
+        Self { intervals, ..self }
+    }
+
+    /// Sets max_gaps field. `-1` allows any number of gaps.
+    pub fn max_gaps<T: Into<i64>>(self, max_gaps: T) -> Self {
+        Self {
+            max_gaps: Some(max_gaps.into()),
+            ..self
+        }
+    }
+
+    pub fn ordered(self, ordered: bool) -> Self {
+        Self {
+            ordered: Some(ordered),
+            ..self
+        }
+    }
+
+    pub fn filter<T: Into<IntervalsFilter>>(self, filter: T) -> Self {
+        Self {
+            filter: Some(filter.into()),
+            ..self
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize)]
+pub struct IntervalsAnyOf {
+    intervals: Vec<IntervalsRule>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<IntervalsFilter>,
+}
+
+impl IntervalsAnyOf {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn intervals<I, T>(self, intervals: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<IntervalsRule>,
+    {
+        Self {
+            intervals: intervals.into_iter().map(|i| i.into()).collect(),
+            ..self
+        }
+    }
+
+    pub fn interval<T: Into<IntervalsRule>>(self, interval: T) -> Self {
+        let mut intervals = self.intervals;
+        intervals.push(interval.into());
+        Self { intervals, ..self }
+    }
+
+    pub fn filter<T: Into<IntervalsFilter>>(self, filter: T) -> Self {
+        Self {
+            filter: Some(filter.into()),
+            ..self
+        }
+    }
+}
+
+/// Restricts the intervals of a rule by their position relative to other intervals.
+#[derive(Debug, Default, Clone, serde::Serialize)]
+pub struct IntervalsFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<IntervalsRule>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<IntervalsRule>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contained_by: Option<IntervalsRule>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    containing: Option<IntervalsRule>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    not_contained_by: Option<IntervalsRule>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    not_containing: Option<IntervalsRule>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    not_overlapping: Option<IntervalsRule>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    overlapping: Option<IntervalsRule>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    script: Option<Script>,
+}
+
+impl IntervalsFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn script<T: Into<Script>>(self, script: T) -> Self {
+        Self {
+            script: Some(script.into()),
+            ..self
+        }
+    }
+}
+
+macro_rules! filter_setter {
+    ($($attr:ident),*) => {
+        impl IntervalsFilter {
+            $(
+                pub fn $attr<T: Into<IntervalsRule>>(self, rule: T) -> Self {
+                    Self {
+                        $attr: Some(rule.into()),
+                        ..self
+                    }
+                }
+            )*
+        }
+    };
+}
+
+filter_setter! {
+    after,
+    before,
+    contained_by,
+    containing,
+    not_contained_by,
+    not_containing,
+    not_overlapping,
+    overlapping
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = Intervals::new().field("title").rule(
+            IntervalsAllOf::new()
+                .ordered(true)
+                .interval(
+                    IntervalsMatch::new()
+                        .query("key-value pairs")
+                        .max_gaps(0)
+                        .ordered(true),
+                )
+                .interval(
+                    IntervalsAnyOf::new()
+                        .interval(IntervalsMatch::new().query("hash table"))
+                        .interval(IntervalsMatch::new().query("hash map")),
+                ),
+        );
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "title": {
+                "all_of": {
+                    "intervals": [
+                        {
+                            "match": {
+                                "query": "key-value pairs",
+                                "max_gaps": 0,
+                                "ordered": true
+                            }
+                        },
+                        {
+                            "any_of": {
+                                "intervals": [
+                                    { "match": { "query": "hash table" } },
+                                    { "match": { "query": "hash map" } }
+                                ]
+                            }
+                        }
+                    ],
+                    "ordered": true
+                }
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_term_rules_to_json() {
+        let query = Intervals::new().field("title").rule(
+            IntervalsAnyOf::new()
+                .interval(IntervalsPrefix::new().prefix("key").use_field("title.raw"))
+                .interval(
+                    IntervalsWildcard::new()
+                        .pattern("hash*")
+                        .analyzer("keyword"),
+                )
+                .interval(
+                    IntervalsFuzzy::new()
+                        .term("tabel")
+                        .fuzziness(Fuzziness::Auto)
+                        .prefix_length(1_u64)
+                        .transpositions(true),
+                ),
+        );
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "title": {
+                "any_of": {
+                    "intervals": [
+                        { "prefix": { "prefix": "key", "use_field": "title.raw" } },
+                        { "wildcard": { "pattern": "hash*", "analyzer": "keyword" } },
+                        {
+                            "fuzzy": {
+                                "term": "tabel",
+                                "fuzziness": "AUTO",
+                                "prefix_length": 1,
+                                "transpositions": true
+                            }
+                        }
+                    ]
+                }
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_filter_to_json() {
+        let query = Intervals::new().field("clause").rule(
+            IntervalsMatch::new()
+                .query("lessee repair")
+                .max_gaps(-1)
+                .analyzer("standard")
+                .filter(
+                    IntervalsFilter::new().not_containing(IntervalsMatch::new().query("landlord")),
+                ),
+        );
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "clause": {
+                "match": {
+                    "query": "lessee repair",
+                    "max_gaps": -1,
+                    "analyzer": "standard",
+                    "filter": {
+                        "not_containing": {
+                            "match": { "query": "landlord" }
+                        }
+                    }
+                }
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_script_filter_to_json() {
+        let query = Intervals::new().field("clause").rule(
+            IntervalsMatch::new()
+                .query("lessee repair")
+                .filter(IntervalsFilter::new().script("interval.start > 10")),
+        );
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "clause": {
+                "match": {
+                    "query": "lessee repair",
+                    "filter": {
+                        "script": { "source": "interval.start > 10" }
+                    }
+                }
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+}
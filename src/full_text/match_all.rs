@@ -1,21 +1,32 @@
-use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde::Serialize;
 
-#[derive(Debug, Default, Clone)]
-pub struct MatchAll;
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct MatchAll {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    boost: Option<f64>,
+
+    #[serde(rename = "_name", skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+}
 
 impl MatchAll {
     pub fn new() -> Self {
         Self::default()
     }
-}
 
-impl Serialize for MatchAll {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        let state = serializer.serialize_map(Some(0))?;
-        state.end()
+    pub fn boost<T: Into<f64>>(self, boost: T) -> Self {
+        Self {
+            boost: Some(boost.into()),
+            ..self
+        }
+    }
+
+    /// Sets _name field, to identify the query in the matched_queries of each hit.
+    pub fn name<T: Into<String>>(self, name: T) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..self
+        }
     }
 }
 
@@ -32,4 +43,18 @@ mod tests {
 
         assert_eq!(json, expected);
     }
+
+    #[test]
+    fn it_serializes_options_to_json() {
+        let query = MatchAll::new().boost(1.2).name("everything");
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "boost": 1.2,
+            "_name": "everything"
+        });
+
+        assert_eq!(json, expected);
+    }
 }
@@ -0,0 +1,60 @@
+use serde::Serialize;
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct MatchNone {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    boost: Option<f64>,
+
+    #[serde(rename = "_name", skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+}
+
+impl MatchNone {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn boost<T: Into<f64>>(self, boost: T) -> Self {
+        Self {
+            boost: Some(boost.into()),
+            ..self
+        }
+    }
+
+    /// Sets _name field, to identify the query in the matched_queries of each hit.
+    pub fn name<T: Into<String>>(self, name: T) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = MatchNone::new();
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({});
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_options_to_json() {
+        let query = MatchNone::new().boost(1.2).name("unauthorized");
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "boost": 1.2,
+            "_name": "unauthorized"
+        });
+
+        assert_eq!(json, expected);
+    }
+}
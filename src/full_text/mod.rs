@@ -1,20 +1,31 @@
 use serde::Serialize;
 
+mod intervals;
 mod match_all;
 mod match_bool_prefix;
+mod match_none;
 mod match_phrase;
 mod match_phrase_prefix;
 mod mtch;
 mod multi_match;
+mod neural;
+mod neural_sparse;
 mod query_string;
 mod simple_query_string;
 
+pub use intervals::{
+    Intervals, IntervalsAllOf, IntervalsAnyOf, IntervalsFilter, IntervalsFuzzy, IntervalsMatch,
+    IntervalsPrefix, IntervalsRule, IntervalsWildcard,
+};
 pub use match_all::MatchAll;
 pub use match_bool_prefix::MatchBoolPrefix;
+pub use match_none::MatchNone;
 pub use match_phrase::MatchPhrase;
 pub use match_phrase_prefix::MatchPhrasePrefix;
 pub use mtch::Match;
 pub use multi_match::MultiMatch;
+pub use neural::Neural;
+pub use neural_sparse::NeuralSparse;
 pub use query_string::QueryString;
 pub use simple_query_string::SimpleQueryString;
 
@@ -29,6 +40,10 @@ pub enum FullText {
     QueryString(Box<QueryString>),
     SimpleQueryString(Box<SimpleQueryString>),
     MatchAll(Box<MatchAll>),
+    MatchNone(Box<MatchNone>),
+    Intervals(Box<Intervals>),
+    Neural(Box<Neural>),
+    NeuralSparse(Box<NeuralSparse>),
 }
 
 macro_rules! from_types {
@@ -51,5 +66,9 @@ from_types! {
     MatchPhrasePrefix,
     QueryString,
     SimpleQueryString,
-    MatchAll
+    MatchAll,
+    MatchNone,
+    Intervals,
+    Neural,
+    NeuralSparse
 }
@@ -0,0 +1,184 @@
+use crate::{options::VectorLimit, QueryClause};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+/// Query of the neural-search plugin, embedding text or an image at search time.
+#[derive(Debug, Default, Clone)]
+pub struct Neural {
+    field: Option<String>,
+    value: NeuralValues,
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize)]
+struct NeuralValues {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_text: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_image: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model_id: Option<String>,
+
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    limit: Option<VectorLimit>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<QueryClause>,
+}
+
+impl Neural {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field<T: Into<String>>(self, field: T) -> Self {
+        Self {
+            field: Some(field.into()),
+            ..self
+        }
+    }
+
+    pub fn query_text<T: Into<String>>(self, query_text: T) -> Self {
+        let value = NeuralValues {
+            query_text: Some(query_text.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    /// Sets query_image field, a base64-encoded image.
+    pub fn query_image<T: Into<String>>(self, query_image: T) -> Self {
+        let value = NeuralValues {
+            query_image: Some(query_image.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn model_id<T: Into<String>>(self, model_id: T) -> Self {
+        let value = NeuralValues {
+            model_id: Some(model_id.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn k<T: Into<u64>>(self, k: T) -> Self {
+        let value = NeuralValues {
+            limit: Some(VectorLimit::K(k.into())),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    /// Sets min_score field, replacing k: returns every document scoring at least this high.
+    pub fn min_score<T: Into<f64>>(self, min_score: T) -> Self {
+        let value = NeuralValues {
+            limit: Some(VectorLimit::MinScore(min_score.into())),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    /// Sets max_distance field, replacing k: returns every document whose embedding is this close.
+    pub fn max_distance<T: Into<f64>>(self, max_distance: T) -> Self {
+        let value = NeuralValues {
+            limit: Some(VectorLimit::MaxDistance(max_distance.into())),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn filter<T: Into<QueryClause>>(self, filter: T) -> Self {
+        let value = NeuralValues {
+            filter: Some(filter.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+}
+
+impl Serialize for Neural {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(1))?;
+        state.serialize_entry(self.field.as_deref().unwrap_or_default(), &self.value)?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::term_level::Term;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = Neural::new()
+            .field("passage_embedding")
+            .query_text("wild west")
+            .query_image("iVBORw0KGgo=")
+            .model_id("aVeif4oB5Vm0Tdw8zYO2")
+            .k(5_u64)
+            .filter(Term::new().field("genre").value("western"));
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "passage_embedding": {
+                "query_text": "wild west",
+                "query_image": "iVBORw0KGgo=",
+                "model_id": "aVeif4oB5Vm0Tdw8zYO2",
+                "k": 5,
+                "filter": {
+                    "term": { "genre": { "value": "western" } }
+                }
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_radial_search_to_json() {
+        let query = Neural::new()
+            .field("passage_embedding")
+            .query_text("wild west")
+            .model_id("aVeif4oB5Vm0Tdw8zYO2")
+            .min_score(0.4);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "passage_embedding": {
+                "query_text": "wild west",
+                "model_id": "aVeif4oB5Vm0Tdw8zYO2",
+                "min_score": 0.4
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_keeps_only_the_last_limit() {
+        let query = Neural::new()
+            .field("passage_embedding")
+            .query_text("wild west")
+            .min_score(0.4)
+            .k(5_u64);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "passage_embedding": {
+                "query_text": "wild west",
+                "k": 5
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+}
@@ -0,0 +1,135 @@
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use std::collections::BTreeMap;
+
+/// Query of the neural-search plugin on a sparse vector field.
+#[derive(Debug, Default, Clone)]
+pub struct NeuralSparse {
+    field: Option<String>,
+    value: NeuralSparseValues,
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize)]
+struct NeuralSparseValues {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query_text: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model_id: Option<String>,
+
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    query_tokens: BTreeMap<String, f64>,
+}
+
+impl NeuralSparse {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field<T: Into<String>>(self, field: T) -> Self {
+        Self {
+            field: Some(field.into()),
+            ..self
+        }
+    }
+
+    pub fn query_text<T: Into<String>>(self, query_text: T) -> Self {
+        let value = NeuralSparseValues {
+            query_text: Some(query_text.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn model_id<T: Into<String>>(self, model_id: T) -> Self {
+        let value = NeuralSparseValues {
+            model_id: Some(model_id.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    /// Sets query_tokens field, pre-computed token weights used instead of query_text.
+    pub fn query_tokens<Q, K, V>(self, query_tokens: Q) -> Self
+    where
+        Q: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<f64>,
+    {
+        let value = NeuralSparseValues {
+            query_tokens: query_tokens
+                .into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn query_token<K: Into<String>, V: Into<f64>>(self, token: K, weight: V) -> Self {
+        let mut query_tokens = self.value.query_tokens;
+        query_tokens.insert(token.into(), weight.into());
+
+        let value = NeuralSparseValues {
+            query_tokens,
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+}
+
+impl Serialize for NeuralSparse {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(1))?;
+        state.serialize_entry(self.field.as_deref().unwrap_or_default(), &self.value)?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_serializes_query_text_to_json() {
+        let query = NeuralSparse::new()
+            .field("passage_embedding")
+            .query_text("Hi world")
+            .model_id("aP2Q8ooBpBj3wT4HVS8a");
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "passage_embedding": {
+                "query_text": "Hi world",
+                "model_id": "aP2Q8ooBpBj3wT4HVS8a"
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_query_tokens_to_json() {
+        let query = NeuralSparse::new()
+            .field("passage_embedding")
+            .query_tokens(vec![("hi", 4.25), ("planets", 2.5)])
+            .query_token("world", 3.125);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "passage_embedding": {
+                "query_tokens": {
+                    "hi": 4.25,
+                    "planets": 2.5,
+                    "world": 3.125
+                }
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+}
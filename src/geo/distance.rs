@@ -0,0 +1,46 @@
+use crate::options::DistanceUnit;
+use serde::ser::{Serialize, Serializer};
+
+/// A distance with its unit, serialized as e.g. `"12km"`.
+#[derive(Debug, Clone)]
+pub struct Distance {
+    value: f64,
+    unit: DistanceUnit,
+}
+
+impl Distance {
+    pub fn new<T: Into<f64>>(value: T, unit: DistanceUnit) -> Self {
+        Self {
+            value: value.into(),
+            unit,
+        }
+    }
+}
+
+impl Serialize for Distance {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&format!("{}{}", self.value, self.unit.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let json = serde_json::to_value(Distance::new(12, DistanceUnit::Kilometers)).unwrap();
+        let expected = serde_json::json!("12km");
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_fractional_distance_to_json() {
+        let json = serde_json::to_value(Distance::new(0.5, DistanceUnit::Miles)).unwrap();
+        let expected = serde_json::json!("0.5mi");
+        assert_eq!(json, expected);
+    }
+}
@@ -0,0 +1,207 @@
+use super::GeoPoint;
+use crate::options::ValidationMethod;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+#[derive(Debug, Default, Clone)]
+pub struct GeoBoundingBox {
+    field: Option<String>,
+    value: BoundingBox,
+    validation_method: Option<ValidationMethod>,
+    ignore_unmapped: Option<bool>,
+    boost: Option<f64>,
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize)]
+struct BoundingBox {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_left: Option<GeoPoint>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bottom_right: Option<GeoPoint>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_right: Option<GeoPoint>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bottom_left: Option<GeoPoint>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    left: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bottom: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    right: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wkt: Option<String>,
+}
+
+impl GeoBoundingBox {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field<T: Into<String>>(self, field: T) -> Self {
+        Self {
+            field: Some(field.into()),
+            ..self
+        }
+    }
+
+    /// Sets wkt field, a box in `BBOX (left, right, top, bottom)` notation.
+    pub fn wkt<T: Into<String>>(self, wkt: T) -> Self {
+        let value = BoundingBox {
+            wkt: Some(wkt.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn validation_method<T: Into<ValidationMethod>>(self, validation_method: T) -> Self {
+        Self {
+            validation_method: Some(validation_method.into()),
+            ..self
+        }
+    }
+
+    pub fn ignore_unmapped(self, ignore_unmapped: bool) -> Self {
+        Self {
+            ignore_unmapped: Some(ignore_unmapped),
+            ..self
+        }
+    }
+
+    pub fn boost<T: Into<f64>>(self, boost: T) -> Self {
+        Self {
+            boost: Some(boost.into()),
+            ..self
+        }
+    }
+}
+
+macro_rules! corner_setter {
+    ($($attr:ident),*) => {
+        impl GeoBoundingBox {
+            $(
+                pub fn $attr<T: Into<GeoPoint>>(self, point: T) -> Self {
+                    let value = BoundingBox {
+                        $attr: Some(point.into()),
+                        ..self.value
+                    };
+                    Self { value, ..self }
+                }
+            )*
+        }
+    };
+}
+
+corner_setter! { top_left, bottom_right, top_right, bottom_left }
+
+macro_rules! side_setter {
+    ($($attr:ident),*) => {
+        impl GeoBoundingBox {
+            $(
+                pub fn $attr<T: Into<f64>>(self, val: T) -> Self {
+                    let value = BoundingBox {
+                        $attr: Some(val.into()),
+                        ..self.value
+                    };
+                    Self { value, ..self }
+                }
+            )*
+        }
+    };
+}
+
+side_setter! { top, left, bottom, right }
+
+impl Serialize for GeoBoundingBox {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(None)?;
+        state.serialize_entry(self.field.as_deref().unwrap_or_default(), &self.value)?;
+        if let Some(validation_method) = self.validation_method.as_ref() {
+            state.serialize_entry("validation_method", validation_method)?;
+        }
+        if let Some(ignore_unmapped) = self.ignore_unmapped.as_ref() {
+            state.serialize_entry("ignore_unmapped", ignore_unmapped)?;
+        }
+        if let Some(boost) = self.boost.as_ref() {
+            state.serialize_entry("boost", boost)?;
+        }
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_serializes_corners_to_json() {
+        let query = GeoBoundingBox::new()
+            .field("point")
+            .top_left(GeoPoint::lat_lon(75, 28))
+            .bottom_right(GeoPoint::geohash("u0"))
+            .validation_method(ValidationMethod::Strict);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "point": {
+                "top_left": { "lat": 75.0, "lon": 28.0 },
+                "bottom_right": "u0"
+            },
+            "validation_method": "STRICT"
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_sides_to_json() {
+        let query = GeoBoundingBox::new()
+            .field("point")
+            .top(75)
+            .left(28)
+            .bottom(73)
+            .right(30);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "point": {
+                "top": 75.0,
+                "left": 28.0,
+                "bottom": 73.0,
+                "right": 30.0
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_wkt_to_json() {
+        let query = GeoBoundingBox::new()
+            .field("point")
+            .wkt("BBOX (28.0, 30.0, 75.0, 73.0)");
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "point": {
+                "wkt": "BBOX (28.0, 30.0, 75.0, 73.0)"
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+}
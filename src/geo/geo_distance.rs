@@ -0,0 +1,124 @@
+use super::{Distance, GeoPoint};
+use crate::options::{DistanceType, ValidationMethod};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+#[derive(Debug, Default, Clone)]
+pub struct GeoDistance {
+    field: Option<String>,
+    point: Option<GeoPoint>,
+    distance: Option<Distance>,
+    distance_type: Option<DistanceType>,
+    validation_method: Option<ValidationMethod>,
+    ignore_unmapped: Option<bool>,
+    boost: Option<f64>,
+}
+
+impl GeoDistance {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field<T: Into<String>>(self, field: T) -> Self {
+        Self {
+            field: Some(field.into()),
+            ..self
+        }
+    }
+
+    /// Sets the point distances are measured from.
+    pub fn point<T: Into<GeoPoint>>(self, point: T) -> Self {
+        Self {
+            point: Some(point.into()),
+            ..self
+        }
+    }
+
+    pub fn distance<T: Into<Distance>>(self, distance: T) -> Self {
+        Self {
+            distance: Some(distance.into()),
+            ..self
+        }
+    }
+
+    pub fn distance_type<T: Into<DistanceType>>(self, distance_type: T) -> Self {
+        Self {
+            distance_type: Some(distance_type.into()),
+            ..self
+        }
+    }
+
+    pub fn validation_method<T: Into<ValidationMethod>>(self, validation_method: T) -> Self {
+        Self {
+            validation_method: Some(validation_method.into()),
+            ..self
+        }
+    }
+
+    pub fn ignore_unmapped(self, ignore_unmapped: bool) -> Self {
+        Self {
+            ignore_unmapped: Some(ignore_unmapped),
+            ..self
+        }
+    }
+
+    pub fn boost<T: Into<f64>>(self, boost: T) -> Self {
+        Self {
+            boost: Some(boost.into()),
+            ..self
+        }
+    }
+}
+
+impl Serialize for GeoDistance {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(None)?;
+        state.serialize_entry("distance", &self.distance)?;
+        state.serialize_entry(self.field.as_deref().unwrap_or_default(), &self.point)?;
+        if let Some(distance_type) = self.distance_type.as_ref() {
+            state.serialize_entry("distance_type", distance_type)?;
+        }
+        if let Some(validation_method) = self.validation_method.as_ref() {
+            state.serialize_entry("validation_method", validation_method)?;
+        }
+        if let Some(ignore_unmapped) = self.ignore_unmapped.as_ref() {
+            state.serialize_entry("ignore_unmapped", ignore_unmapped)?;
+        }
+        if let Some(boost) = self.boost.as_ref() {
+            state.serialize_entry("boost", boost)?;
+        }
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::DistanceUnit;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = GeoDistance::new()
+            .field("point")
+            .point(GeoPoint::lat_lon(74, 40.71))
+            .distance(Distance::new(50, DistanceUnit::Kilometers))
+            .distance_type(DistanceType::Plane)
+            .validation_method(ValidationMethod::Coerce);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "distance": "50km",
+            "point": {
+                "lat": 74.0,
+                "lon": 40.71
+            },
+            "distance_type": "plane",
+            "validation_method": "COERCE"
+        });
+
+        assert_eq!(json, expected);
+    }
+}
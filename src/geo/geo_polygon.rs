@@ -0,0 +1,120 @@
+use super::GeoPoint;
+use crate::options::ValidationMethod;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+#[derive(Debug, Default, Clone)]
+pub struct GeoPolygon {
+    field: Option<String>,
+    value: GeoPolygonValues,
+    validation_method: Option<ValidationMethod>,
+    ignore_unmapped: Option<bool>,
+    boost: Option<f64>,
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize)]
+struct GeoPolygonValues {
+    points: Vec<GeoPoint>,
+}
+
+impl GeoPolygon {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field<T: Into<String>>(self, field: T) -> Self {
+        Self {
+            field: Some(field.into()),
+            ..self
+        }
+    }
+
+    pub fn points<P, T>(self, points: P) -> Self
+    where
+        P: IntoIterator<Item = T>,
+        T: Into<GeoPoint>,
+    {
+        let value = GeoPolygonValues {
+            points: points.into_iter().map(|p| p.into()).collect(),
+        };
+        Self { value, ..self }
+    }
+
+    pub fn point<T: Into<GeoPoint>>(self, point: T) -> Self {
+        let mut points = self.value.points;
+        points.push(point.into());
+
+        let value = GeoPolygonValues { points };
+        Self { value, ..self }
+    }
+
+    pub fn validation_method<T: Into<ValidationMethod>>(self, validation_method: T) -> Self {
+        Self {
+            validation_method: Some(validation_method.into()),
+            ..self
+        }
+    }
+
+    pub fn ignore_unmapped(self, ignore_unmapped: bool) -> Self {
+        Self {
+            ignore_unmapped: Some(ignore_unmapped),
+            ..self
+        }
+    }
+
+    pub fn boost<T: Into<f64>>(self, boost: T) -> Self {
+        Self {
+            boost: Some(boost.into()),
+            ..self
+        }
+    }
+}
+
+impl Serialize for GeoPolygon {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(None)?;
+        state.serialize_entry(self.field.as_deref().unwrap_or_default(), &self.value)?;
+        if let Some(validation_method) = self.validation_method.as_ref() {
+            state.serialize_entry("validation_method", validation_method)?;
+        }
+        if let Some(ignore_unmapped) = self.ignore_unmapped.as_ref() {
+            state.serialize_entry("ignore_unmapped", ignore_unmapped)?;
+        }
+        if let Some(boost) = self.boost.as_ref() {
+            state.serialize_entry("boost", boost)?;
+        }
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = GeoPolygon::new()
+            .field("point")
+            .point(GeoPoint::lat_lon(74.5627, 41.8645))
+            .point([40.0, 73.7562])
+            .point(GeoPoint::text(73.7562, 42.6526))
+            .validation_method(ValidationMethod::IgnoreMalformed);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "point": {
+                "points": [
+                    { "lat": 74.5627, "lon": 41.8645 },
+                    [40.0, 73.7562],
+                    "73.7562,42.6526"
+                ]
+            },
+            "validation_method": "IGNORE_MALFORMED"
+        });
+
+        assert_eq!(json, expected);
+    }
+}
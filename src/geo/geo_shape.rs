@@ -0,0 +1,184 @@
+use super::{Geometry, IndexedShape};
+use crate::options::SpatialRelation;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+#[derive(Debug, Default, Clone)]
+pub struct GeoShape {
+    field: Option<String>,
+    value: ShapeValues,
+    ignore_unmapped: Option<bool>,
+}
+
+/// The field-keyed body shared by the shape queries.
+#[derive(Debug, Clone, serde::Serialize)]
+pub(super) struct ShapeValues<R = SpatialRelation> {
+    #[serde(flatten)]
+    pub(super) shape: Option<ShapeSource>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) relation: Option<R>,
+}
+
+impl<R> Default for ShapeValues<R> {
+    fn default() -> Self {
+        Self {
+            shape: None,
+            relation: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum ShapeSource {
+    Shape(Geometry),
+    #[serde(rename = "shape")]
+    Wkt(String),
+    IndexedShape(IndexedShape),
+}
+
+impl GeoShape {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field<T: Into<String>>(self, field: T) -> Self {
+        Self {
+            field: Some(field.into()),
+            ..self
+        }
+    }
+
+    /// Sets an inline shape in GeoJSON notation.
+    pub fn shape<T: Into<Geometry>>(self, shape: T) -> Self {
+        let value = ShapeValues {
+            shape: Some(ShapeSource::Shape(shape.into())),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    /// Sets an inline shape in WKT notation.
+    pub fn wkt_shape<T: Into<Geometry>>(self, shape: T) -> Self {
+        let value = ShapeValues {
+            shape: Some(ShapeSource::Wkt(shape.into().to_wkt())),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn indexed_shape<T: Into<IndexedShape>>(self, indexed_shape: T) -> Self {
+        let value = ShapeValues {
+            shape: Some(ShapeSource::IndexedShape(indexed_shape.into())),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn relation<T: Into<SpatialRelation>>(self, relation: T) -> Self {
+        let value = ShapeValues {
+            relation: Some(relation.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn ignore_unmapped(self, ignore_unmapped: bool) -> Self {
+        Self {
+            ignore_unmapped: Some(ignore_unmapped),
+            ..self
+        }
+    }
+}
+
+impl Serialize for GeoShape {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(None)?;
+        state.serialize_entry(self.field.as_deref().unwrap_or_default(), &self.value)?;
+        if let Some(ignore_unmapped) = self.ignore_unmapped.as_ref() {
+            state.serialize_entry("ignore_unmapped", ignore_unmapped)?;
+        }
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geo::{Envelope, Polygon};
+
+    #[test]
+    fn it_serializes_geojson_shape_to_json() {
+        let query = GeoShape::new()
+            .field("location")
+            .shape(Polygon::new(vec![
+                [74.0, 40.0],
+                [75.0, 40.0],
+                [75.0, 41.0],
+                [74.0, 40.0],
+            ]))
+            .relation(SpatialRelation::Within)
+            .ignore_unmapped(true);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "location": {
+                "shape": {
+                    "type": "Polygon",
+                    "coordinates": [
+                        [[74.0, 40.0], [75.0, 40.0], [75.0, 41.0], [74.0, 40.0]]
+                    ]
+                },
+                "relation": "within"
+            },
+            "ignore_unmapped": true
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_wkt_shape_to_json() {
+        let query = GeoShape::new()
+            .field("location")
+            .wkt_shape(Envelope::new([71.0, 74.0], [73.0, 71.0]))
+            .relation(SpatialRelation::Disjoint);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "location": {
+                "shape": "BBOX (71, 73, 74, 71)",
+                "relation": "disjoint"
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_indexed_shape_to_json() {
+        let query = GeoShape::new()
+            .field("location")
+            .indexed_shape(IndexedShape::new().index("zones").id("zone-1"))
+            .relation(SpatialRelation::Contains);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "location": {
+                "indexed_shape": {
+                    "index": "zones",
+                    "id": "zone-1"
+                },
+                "relation": "contains"
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+}
@@ -0,0 +1,74 @@
+use serde::Serialize;
+
+/// A reference to a shape stored in a document of another index.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct IndexedShape {
+    index: Option<String>,
+    id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    routing: Option<String>,
+}
+
+impl IndexedShape {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn index<T: Into<String>>(self, index: T) -> Self {
+        Self {
+            index: Some(index.into()),
+            ..self
+        }
+    }
+
+    pub fn id<T: Into<String>>(self, id: T) -> Self {
+        Self {
+            id: Some(id.into()),
+            ..self
+        }
+    }
+
+    /// Sets path field, the field holding the shape. Defaults to `shape`.
+    pub fn path<T: Into<String>>(self, path: T) -> Self {
+        Self {
+            path: Some(path.into()),
+            ..self
+        }
+    }
+
+    pub fn routing<T: Into<String>>(self, routing: T) -> Self {
+        Self {
+            routing: Some(routing.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let shape = IndexedShape::new()
+            .index("zones")
+            .id("zone-1")
+            .path("boundary")
+            .routing("eu");
+
+        let json = serde_json::to_value(shape).unwrap();
+
+        let expected = serde_json::json!({
+            "index": "zones",
+            "id": "zone-1",
+            "path": "boundary",
+            "routing": "eu"
+        });
+
+        assert_eq!(json, expected);
+    }
+}
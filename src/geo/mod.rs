@@ -0,0 +1,58 @@
+use serde::Serialize;
+
+mod distance;
+mod geo_bounding_box;
+mod geo_distance;
+mod geo_polygon;
+mod geo_shape;
+mod indexed_shape;
+mod point;
+mod shape;
+mod xy_point;
+mod xy_shape;
+
+pub use distance::Distance;
+pub use geo_bounding_box::GeoBoundingBox;
+pub use geo_distance::GeoDistance;
+pub use geo_polygon::GeoPolygon;
+pub use geo_shape::GeoShape;
+pub use indexed_shape::IndexedShape;
+pub use point::GeoPoint;
+pub use shape::{
+    Coordinate, Envelope, Geometry, GeometryCollection, LineString, MultiPolygon, Point, Polygon,
+};
+pub use xy_point::XyPoint;
+pub use xy_shape::XyShape;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Geo {
+    GeoDistance(Box<GeoDistance>),
+    GeoBoundingBox(Box<GeoBoundingBox>),
+    GeoPolygon(Box<GeoPolygon>),
+    GeoShape(Box<GeoShape>),
+    XyShape(Box<XyShape>),
+    #[serde(rename = "xy_shape")]
+    XyPoint(Box<XyPoint>),
+}
+
+macro_rules! from_types {
+    ($($ty:ident),*) => {
+        $(
+            impl From<$ty> for Geo {
+                fn from(val: $ty) -> Self {
+                    Self::$ty(Box::new(val.into()))
+                }
+            }
+        )*
+    }
+}
+
+from_types! {
+    GeoDistance,
+    GeoBoundingBox,
+    GeoPolygon,
+    GeoShape,
+    XyShape,
+    XyPoint
+}
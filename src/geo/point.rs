@@ -0,0 +1,99 @@
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+/// A geographic point in any of the notations OpenSearch accepts.
+#[derive(Debug, Clone)]
+pub enum GeoPoint {
+    /// `{ "lat": 40.71, "lon": 74.00 }`
+    LatLon { lat: f64, lon: f64 },
+    /// `[74.00, 40.71]`, longitude first as in GeoJSON.
+    Array { lon: f64, lat: f64 },
+    /// `"40.71,74.00"`
+    Text { lat: f64, lon: f64 },
+    /// `"drm3btev3e86"`
+    Geohash(String),
+}
+
+impl GeoPoint {
+    pub fn lat_lon<T: Into<f64>, U: Into<f64>>(lat: T, lon: U) -> Self {
+        Self::LatLon {
+            lat: lat.into(),
+            lon: lon.into(),
+        }
+    }
+
+    pub fn array<T: Into<f64>, U: Into<f64>>(lon: T, lat: U) -> Self {
+        Self::Array {
+            lon: lon.into(),
+            lat: lat.into(),
+        }
+    }
+
+    pub fn text<T: Into<f64>, U: Into<f64>>(lat: T, lon: U) -> Self {
+        Self::Text {
+            lat: lat.into(),
+            lon: lon.into(),
+        }
+    }
+
+    pub fn geohash<T: Into<String>>(geohash: T) -> Self {
+        Self::Geohash(geohash.into())
+    }
+}
+
+impl From<[f64; 2]> for GeoPoint {
+    fn from([lon, lat]: [f64; 2]) -> Self {
+        Self::array(lon, lat)
+    }
+}
+
+impl Serialize for GeoPoint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            GeoPoint::LatLon { lat, lon } => {
+                let mut state = serializer.serialize_map(Some(2))?;
+                state.serialize_entry("lat", lat)?;
+                state.serialize_entry("lon", lon)?;
+                state.end()
+            }
+            GeoPoint::Array { lon, lat } => [lon, lat].serialize(serializer),
+            GeoPoint::Text { lat, lon } => serializer.serialize_str(&format!("{lat},{lon}")),
+            GeoPoint::Geohash(geohash) => serializer.serialize_str(geohash),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_serializes_lat_lon_to_json() {
+        let json = serde_json::to_value(GeoPoint::lat_lon(40.71, 74.0)).unwrap();
+        let expected = serde_json::json!({ "lat": 40.71, "lon": 74.0 });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_array_to_json() {
+        let json = serde_json::to_value(GeoPoint::from([74.0, 40.71])).unwrap();
+        let expected = serde_json::json!([74.0, 40.71]);
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_text_to_json() {
+        let json = serde_json::to_value(GeoPoint::text(40.71, 74.5)).unwrap();
+        let expected = serde_json::json!("40.71,74.5");
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_geohash_to_json() {
+        let json = serde_json::to_value(GeoPoint::geohash("drm3btev3e86")).unwrap();
+        let expected = serde_json::json!("drm3btev3e86");
+        assert_eq!(json, expected);
+    }
+}
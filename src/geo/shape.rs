@@ -0,0 +1,440 @@
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+/// A coordinate pair, `[lon, lat]` for geographic shapes and `[x, y]` for cartesian ones.
+pub type Coordinate = [f64; 2];
+
+/// A geometry which serializes to GeoJSON and converts to WKT with `to_wkt`.
+#[derive(Debug, Clone)]
+pub enum Geometry {
+    Point(Point),
+    LineString(LineString),
+    Polygon(Polygon),
+    MultiPolygon(MultiPolygon),
+    Envelope(Envelope),
+    GeometryCollection(GeometryCollection),
+}
+
+impl Geometry {
+    pub fn to_wkt(&self) -> String {
+        match self {
+            Geometry::Point(point) => point.to_wkt(),
+            Geometry::LineString(line_string) => line_string.to_wkt(),
+            Geometry::Polygon(polygon) => polygon.to_wkt(),
+            Geometry::MultiPolygon(multi_polygon) => multi_polygon.to_wkt(),
+            Geometry::Envelope(envelope) => envelope.to_wkt(),
+            Geometry::GeometryCollection(collection) => collection.to_wkt(),
+        }
+    }
+}
+
+impl Serialize for Geometry {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Geometry::Point(point) => point.serialize(serializer),
+            Geometry::LineString(line_string) => line_string.serialize(serializer),
+            Geometry::Polygon(polygon) => polygon.serialize(serializer),
+            Geometry::MultiPolygon(multi_polygon) => multi_polygon.serialize(serializer),
+            Geometry::Envelope(envelope) => envelope.serialize(serializer),
+            Geometry::GeometryCollection(collection) => collection.serialize(serializer),
+        }
+    }
+}
+
+macro_rules! from_types {
+    ($($ty:ident),*) => {
+        $(
+            impl From<$ty> for Geometry {
+                fn from(val: $ty) -> Self {
+                    Self::$ty(val)
+                }
+            }
+        )*
+    }
+}
+
+from_types! {
+    Point,
+    LineString,
+    Polygon,
+    MultiPolygon,
+    Envelope,
+    GeometryCollection
+}
+
+#[derive(Debug, Clone)]
+pub struct Point {
+    coordinates: Coordinate,
+}
+
+impl Point {
+    pub fn new<T: Into<f64>, U: Into<f64>>(x: T, y: U) -> Self {
+        Self {
+            coordinates: [x.into(), y.into()],
+        }
+    }
+
+    pub fn to_wkt(&self) -> String {
+        format!("POINT ({})", wkt_coordinate(&self.coordinates))
+    }
+}
+
+impl Serialize for Point {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(2))?;
+        state.serialize_entry("type", "Point")?;
+        state.serialize_entry("coordinates", &self.coordinates)?;
+        state.end()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LineString {
+    coordinates: Vec<Coordinate>,
+}
+
+impl LineString {
+    pub fn new<C: IntoIterator<Item = Coordinate>>(coordinates: C) -> Self {
+        Self {
+            coordinates: coordinates.into_iter().collect(),
+        }
+    }
+
+    pub fn to_wkt(&self) -> String {
+        format!("LINESTRING {}", wkt_coordinates(&self.coordinates))
+    }
+}
+
+impl Serialize for LineString {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(2))?;
+        state.serialize_entry("type", "LineString")?;
+        state.serialize_entry("coordinates", &self.coordinates)?;
+        state.end()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Polygon {
+    rings: Vec<Vec<Coordinate>>,
+}
+
+impl Polygon {
+    /// Creates a polygon from its outer ring. The first and last coordinates should be equal.
+    pub fn new<C: IntoIterator<Item = Coordinate>>(exterior: C) -> Self {
+        Self {
+            rings: vec![exterior.into_iter().collect()],
+        }
+    }
+
+    /// Adds an inner ring cut out of the polygon.
+    pub fn hole<C: IntoIterator<Item = Coordinate>>(self, ring: C) -> Self {
+        let mut rings = self.rings;
+        rings.push(ring.into_iter().collect());
+        Self { rings }
+    }
+
+    pub fn to_wkt(&self) -> String {
+        format!("POLYGON {}", wkt_rings(&self.rings))
+    }
+}
+
+impl Serialize for Polygon {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(2))?;
+        state.serialize_entry("type", "Polygon")?;
+        state.serialize_entry("coordinates", &self.rings)?;
+        state.end()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MultiPolygon {
+    polygons: Vec<Polygon>,
+}
+
+impl MultiPolygon {
+    pub fn new<P: IntoIterator<Item = Polygon>>(polygons: P) -> Self {
+        Self {
+            polygons: polygons.into_iter().collect(),
+        }
+    }
+
+    pub fn to_wkt(&self) -> String {
+        let polygons = self.polygons.iter().map(|p| wkt_rings(&p.rings));
+        format!("MULTIPOLYGON {}", wkt_list(polygons))
+    }
+}
+
+impl Serialize for MultiPolygon {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let coordinates: Vec<&Vec<Vec<Coordinate>>> =
+            self.polygons.iter().map(|p| &p.rings).collect();
+
+        let mut state = serializer.serialize_map(Some(2))?;
+        state.serialize_entry("type", "MultiPolygon")?;
+        state.serialize_entry("coordinates", &coordinates)?;
+        state.end()
+    }
+}
+
+/// A rectangle given by its upper left and lower right corners.
+#[derive(Debug, Clone)]
+pub struct Envelope {
+    top_left: Coordinate,
+    bottom_right: Coordinate,
+}
+
+impl Envelope {
+    pub fn new(top_left: Coordinate, bottom_right: Coordinate) -> Self {
+        Self {
+            top_left,
+            bottom_right,
+        }
+    }
+
+    pub fn to_wkt(&self) -> String {
+        let [min_x, max_y] = self.top_left;
+        let [max_x, min_y] = self.bottom_right;
+        format!("BBOX ({min_x}, {max_x}, {max_y}, {min_y})")
+    }
+}
+
+impl Serialize for Envelope {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(2))?;
+        state.serialize_entry("type", "envelope")?;
+        state.serialize_entry("coordinates", &[self.top_left, self.bottom_right])?;
+        state.end()
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GeometryCollection {
+    geometries: Vec<Geometry>,
+}
+
+impl GeometryCollection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn geometry<T: Into<Geometry>>(self, geometry: T) -> Self {
+        let mut geometries = self.geometries;
+        geometries.push(geometry.into());
+        Self { geometries }
+    }
+
+    pub fn to_wkt(&self) -> String {
+        let geometries = self.geometries.iter().map(|g| g.to_wkt());
+        format!("GEOMETRYCOLLECTION {}", wkt_list(geometries))
+    }
+}
+
+impl Serialize for GeometryCollection {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(2))?;
+        state.serialize_entry("type", "GeometryCollection")?;
+        state.serialize_entry("geometries", &self.geometries)?;
+        state.end()
+    }
+}
+
+fn wkt_coordinate([x, y]: &Coordinate) -> String {
+    format!("{x} {y}")
+}
+
+fn wkt_coordinates(coordinates: &[Coordinate]) -> String {
+    wkt_list(coordinates.iter().map(wkt_coordinate))
+}
+
+fn wkt_rings(rings: &[Vec<Coordinate>]) -> String {
+    // A polygon without an exterior ring is empty, whatever its holes.
+    match rings.first() {
+        Some(exterior) if !exterior.is_empty() => {
+            wkt_list(rings.iter().map(|r| wkt_coordinates(r)))
+        }
+        _ => wkt_list(std::iter::empty()),
+    }
+}
+
+/// Joins the members of a geometry in parentheses, or returns `EMPTY` when there are none.
+fn wkt_list<I: Iterator<Item = String>>(members: I) -> String {
+    let members = members.collect::<Vec<String>>();
+    if members.is_empty() {
+        "EMPTY".into()
+    } else {
+        format!("({})", members.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square() -> Polygon {
+        Polygon::new(vec![
+            [74.0, 40.0],
+            [75.0, 40.0],
+            [75.0, 41.0],
+            [74.0, 41.0],
+            [74.0, 40.0],
+        ])
+    }
+
+    #[test]
+    fn it_serializes_point() {
+        let point = Point::new(74, 40.71);
+
+        let json = serde_json::to_value(&point).unwrap();
+        let expected = serde_json::json!({
+            "type": "Point",
+            "coordinates": [74.0, 40.71]
+        });
+
+        assert_eq!(json, expected);
+        assert_eq!(point.to_wkt(), "POINT (74 40.71)");
+    }
+
+    #[test]
+    fn it_serializes_line_string() {
+        let line = LineString::new(vec![[74.0, 40.71], [73.9, 40.8]]);
+
+        let json = serde_json::to_value(&line).unwrap();
+        let expected = serde_json::json!({
+            "type": "LineString",
+            "coordinates": [[74.0, 40.71], [73.9, 40.8]]
+        });
+
+        assert_eq!(json, expected);
+        assert_eq!(line.to_wkt(), "LINESTRING (74 40.71, 73.9 40.8)");
+    }
+
+    #[test]
+    fn it_serializes_polygon_with_hole() {
+        let polygon = square().hole(vec![[74.2, 40.2], [74.4, 40.2], [74.4, 40.4], [74.2, 40.2]]);
+
+        let json = serde_json::to_value(&polygon).unwrap();
+        let expected = serde_json::json!({
+            "type": "Polygon",
+            "coordinates": [
+                [[74.0, 40.0], [75.0, 40.0], [75.0, 41.0], [74.0, 41.0], [74.0, 40.0]],
+                [[74.2, 40.2], [74.4, 40.2], [74.4, 40.4], [74.2, 40.2]]
+            ]
+        });
+
+        assert_eq!(json, expected);
+        assert_eq!(
+            polygon.to_wkt(),
+            "POLYGON ((74 40, 75 40, 75 41, 74 41, 74 40), \
+             (74.2 40.2, 74.4 40.2, 74.4 40.4, 74.2 40.2))"
+        );
+    }
+
+    #[test]
+    fn it_serializes_multi_polygon() {
+        let multi = MultiPolygon::new(vec![square(), square()]);
+
+        let json = serde_json::to_value(&multi).unwrap();
+        let ring = serde_json::json!([
+            [74.0, 40.0],
+            [75.0, 40.0],
+            [75.0, 41.0],
+            [74.0, 41.0],
+            [74.0, 40.0]
+        ]);
+        let expected = serde_json::json!({
+            "type": "MultiPolygon",
+            "coordinates": [[ring], [ring]]
+        });
+
+        assert_eq!(json, expected);
+        assert_eq!(
+            multi.to_wkt(),
+            "MULTIPOLYGON (((74 40, 75 40, 75 41, 74 41, 74 40)), \
+             ((74 40, 75 40, 75 41, 74 41, 74 40)))"
+        );
+    }
+
+    #[test]
+    fn it_serializes_envelope() {
+        let envelope = Envelope::new([71.0, 74.0], [73.0, 71.0]);
+
+        let json = serde_json::to_value(&envelope).unwrap();
+        let expected = serde_json::json!({
+            "type": "envelope",
+            "coordinates": [[71.0, 74.0], [73.0, 71.0]]
+        });
+
+        assert_eq!(json, expected);
+        assert_eq!(envelope.to_wkt(), "BBOX (71, 73, 74, 71)");
+    }
+
+    #[test]
+    fn it_serializes_geometry_collection() {
+        let collection = GeometryCollection::new()
+            .geometry(Point::new(74, 40))
+            .geometry(LineString::new(vec![[74.0, 40.0], [75.0, 41.0]]));
+
+        let json = serde_json::to_value(&collection).unwrap();
+        let expected = serde_json::json!({
+            "type": "GeometryCollection",
+            "geometries": [
+                { "type": "Point", "coordinates": [74.0, 40.0] },
+                { "type": "LineString", "coordinates": [[74.0, 40.0], [75.0, 41.0]] }
+            ]
+        });
+
+        assert_eq!(json, expected);
+        assert_eq!(
+            collection.to_wkt(),
+            "GEOMETRYCOLLECTION (POINT (74 40), LINESTRING (74 40, 75 41))"
+        );
+    }
+
+    #[test]
+    fn it_renders_empty_geometries_as_wkt() {
+        assert_eq!(
+            LineString::new(Vec::<Coordinate>::new()).to_wkt(),
+            "LINESTRING EMPTY"
+        );
+        assert_eq!(
+            Polygon::new(Vec::<Coordinate>::new()).to_wkt(),
+            "POLYGON EMPTY"
+        );
+        assert_eq!(
+            MultiPolygon::new(Vec::<Polygon>::new()).to_wkt(),
+            "MULTIPOLYGON EMPTY"
+        );
+        assert_eq!(
+            GeometryCollection::new().to_wkt(),
+            "GEOMETRYCOLLECTION EMPTY"
+        );
+        assert_eq!(
+            GeometryCollection::new()
+                .geometry(LineString::new(Vec::<Coordinate>::new()))
+                .to_wkt(),
+            "GEOMETRYCOLLECTION (LINESTRING EMPTY)"
+        );
+    }
+}
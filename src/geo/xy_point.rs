@@ -0,0 +1,162 @@
+use super::geo_shape::{ShapeSource, ShapeValues};
+use super::{Geometry, IndexedShape};
+use crate::options::PointRelation;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+/// Query on an `xy_point` field, relating its points to a shape on the Cartesian plane.
+///
+/// There is no `xy_point` query in OpenSearch: it serializes as an `xy_shape` query, and a
+/// point can't contain a shape, so the relation is limited to [`PointRelation`].
+#[derive(Debug, Default, Clone)]
+pub struct XyPoint {
+    field: Option<String>,
+    value: ShapeValues<PointRelation>,
+    ignore_unmapped: Option<bool>,
+}
+
+impl XyPoint {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field<T: Into<String>>(self, field: T) -> Self {
+        Self {
+            field: Some(field.into()),
+            ..self
+        }
+    }
+
+    pub fn shape<T: Into<Geometry>>(self, shape: T) -> Self {
+        let value = ShapeValues {
+            shape: Some(ShapeSource::Shape(shape.into())),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn wkt_shape<T: Into<Geometry>>(self, shape: T) -> Self {
+        let value = ShapeValues {
+            shape: Some(ShapeSource::Wkt(shape.into().to_wkt())),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn indexed_shape<T: Into<IndexedShape>>(self, indexed_shape: T) -> Self {
+        let value = ShapeValues {
+            shape: Some(ShapeSource::IndexedShape(indexed_shape.into())),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn relation<T: Into<PointRelation>>(self, relation: T) -> Self {
+        let value = ShapeValues {
+            relation: Some(relation.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn ignore_unmapped(self, ignore_unmapped: bool) -> Self {
+        Self {
+            ignore_unmapped: Some(ignore_unmapped),
+            ..self
+        }
+    }
+}
+
+impl Serialize for XyPoint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(None)?;
+        state.serialize_entry(self.field.as_deref().unwrap_or_default(), &self.value)?;
+        if let Some(ignore_unmapped) = self.ignore_unmapped.as_ref() {
+            state.serialize_entry("ignore_unmapped", ignore_unmapped)?;
+        }
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geo::{Geo, Point, Polygon};
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = XyPoint::new()
+            .field("desk")
+            .shape(Polygon::new(vec![
+                [0.0, 0.0],
+                [4.0, 0.0],
+                [4.0, 4.0],
+                [0.0, 0.0],
+            ]))
+            .relation(PointRelation::Within);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "desk": {
+                "shape": {
+                    "type": "Polygon",
+                    "coordinates": [[[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 0.0]]]
+                },
+                "relation": "within"
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_indexed_shape_to_json() {
+        let query = XyPoint::new()
+            .field("desk")
+            .indexed_shape(
+                IndexedShape::new()
+                    .index("rooms")
+                    .id("meeting-room")
+                    .path("area"),
+            )
+            .relation(PointRelation::Disjoint);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "desk": {
+                "indexed_shape": {
+                    "index": "rooms",
+                    "id": "meeting-room",
+                    "path": "area"
+                },
+                "relation": "disjoint"
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_as_xy_shape_query() {
+        let query = XyPoint::new().field("desk").shape(Point::new(1, 2));
+
+        let json = serde_json::to_value(Geo::from(query)).unwrap();
+
+        let expected = serde_json::json!({
+            "xy_shape": {
+                "desk": {
+                    "shape": {
+                        "type": "Point",
+                        "coordinates": [1.0, 2.0]
+                    }
+                }
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+}
@@ -0,0 +1,126 @@
+use super::geo_shape::{ShapeSource, ShapeValues};
+use super::{Geometry, IndexedShape};
+use crate::options::SpatialRelation;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+/// Query on an `xy_shape` field. Unlike `geo_shape`, coordinates are plain `[x, y]` values on a
+/// Cartesian plane: they are neither longitudes and latitudes nor bounded to their ranges.
+#[derive(Debug, Default, Clone)]
+pub struct XyShape {
+    field: Option<String>,
+    value: ShapeValues,
+    ignore_unmapped: Option<bool>,
+}
+
+impl XyShape {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field<T: Into<String>>(self, field: T) -> Self {
+        Self {
+            field: Some(field.into()),
+            ..self
+        }
+    }
+
+    pub fn shape<T: Into<Geometry>>(self, shape: T) -> Self {
+        let value = ShapeValues {
+            shape: Some(ShapeSource::Shape(shape.into())),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn wkt_shape<T: Into<Geometry>>(self, shape: T) -> Self {
+        let value = ShapeValues {
+            shape: Some(ShapeSource::Wkt(shape.into().to_wkt())),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn indexed_shape<T: Into<IndexedShape>>(self, indexed_shape: T) -> Self {
+        let value = ShapeValues {
+            shape: Some(ShapeSource::IndexedShape(indexed_shape.into())),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn relation<T: Into<SpatialRelation>>(self, relation: T) -> Self {
+        let value = ShapeValues {
+            relation: Some(relation.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn ignore_unmapped(self, ignore_unmapped: bool) -> Self {
+        Self {
+            ignore_unmapped: Some(ignore_unmapped),
+            ..self
+        }
+    }
+}
+
+impl Serialize for XyShape {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(None)?;
+        state.serialize_entry(self.field.as_deref().unwrap_or_default(), &self.value)?;
+        if let Some(ignore_unmapped) = self.ignore_unmapped.as_ref() {
+            state.serialize_entry("ignore_unmapped", ignore_unmapped)?;
+        }
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geo::{Envelope, Point};
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = XyShape::new()
+            .field("floor_plan")
+            .shape(Envelope::new([0.0, 10.0], [10.0, 0.0]))
+            .relation(SpatialRelation::Contains);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "floor_plan": {
+                "shape": {
+                    "type": "envelope",
+                    "coordinates": [[0.0, 10.0], [10.0, 0.0]]
+                },
+                "relation": "contains"
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_wkt_shape_to_json() {
+        let query = XyShape::new()
+            .field("floor_plan")
+            .wkt_shape(Point::new(3.5, 7))
+            .ignore_unmapped(true);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "floor_plan": {
+                "shape": "POINT (3.5 7)"
+            },
+            "ignore_unmapped": true
+        });
+
+        assert_eq!(json, expected);
+    }
+}
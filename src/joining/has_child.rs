@@ -0,0 +1,117 @@
+use super::InnerHits;
+use crate::options::ScoreMode;
+use crate::QueryClause;
+use serde::Serialize;
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct HasChild {
+    #[serde(rename = "type")]
+    typ: Option<String>,
+
+    query: Option<QueryClause>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    score_mode: Option<ScoreMode>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_children: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_children: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_unmapped: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inner_hits: Option<InnerHits>,
+}
+
+impl HasChild {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets type field, the name of the child relation.
+    pub fn typ<T: Into<String>>(self, typ: T) -> Self {
+        Self {
+            typ: Some(typ.into()),
+            ..self
+        }
+    }
+
+    pub fn query<T: Into<QueryClause>>(self, query: T) -> Self {
+        Self {
+            query: Some(query.into()),
+            ..self
+        }
+    }
+
+    pub fn score_mode<T: Into<ScoreMode>>(self, score_mode: T) -> Self {
+        Self {
+            score_mode: Some(score_mode.into()),
+            ..self
+        }
+    }
+
+    pub fn min_children<T: Into<u64>>(self, min_children: T) -> Self {
+        Self {
+            min_children: Some(min_children.into()),
+            ..self
+        }
+    }
+
+    pub fn max_children<T: Into<u64>>(self, max_children: T) -> Self {
+        Self {
+            max_children: Some(max_children.into()),
+            ..self
+        }
+    }
+
+    pub fn ignore_unmapped(self, ignore_unmapped: bool) -> Self {
+        Self {
+            ignore_unmapped: Some(ignore_unmapped),
+            ..self
+        }
+    }
+
+    pub fn inner_hits<T: Into<InnerHits>>(self, inner_hits: T) -> Self {
+        Self {
+            inner_hits: Some(inner_hits.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::full_text::Match;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = HasChild::new()
+            .typ("answer")
+            .query(Match::new().field("body").value("tokio runtime"))
+            .score_mode(ScoreMode::Max)
+            .min_children(1_u64)
+            .max_children(10_u64)
+            .inner_hits(InnerHits::new().size(2_u64));
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "type": "answer",
+            "query": {
+                "match": { "body": { "query": "tokio runtime" } }
+            },
+            "score_mode": "max",
+            "min_children": 1,
+            "max_children": 10,
+            "inner_hits": {
+                "size": 2
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+}
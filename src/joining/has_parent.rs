@@ -0,0 +1,89 @@
+use super::InnerHits;
+use crate::QueryClause;
+use serde::Serialize;
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct HasParent {
+    parent_type: Option<String>,
+    query: Option<QueryClause>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    score: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_unmapped: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inner_hits: Option<InnerHits>,
+}
+
+impl HasParent {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn parent_type<T: Into<String>>(self, parent_type: T) -> Self {
+        Self {
+            parent_type: Some(parent_type.into()),
+            ..self
+        }
+    }
+
+    pub fn query<T: Into<QueryClause>>(self, query: T) -> Self {
+        Self {
+            query: Some(query.into()),
+            ..self
+        }
+    }
+
+    pub fn score(self, score: bool) -> Self {
+        Self {
+            score: Some(score),
+            ..self
+        }
+    }
+
+    pub fn ignore_unmapped(self, ignore_unmapped: bool) -> Self {
+        Self {
+            ignore_unmapped: Some(ignore_unmapped),
+            ..self
+        }
+    }
+
+    pub fn inner_hits<T: Into<InnerHits>>(self, inner_hits: T) -> Self {
+        Self {
+            inner_hits: Some(inner_hits.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::term_level::Term;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = HasParent::new()
+            .parent_type("question")
+            .query(Term::new().field("tags").value("rust"))
+            .score(true)
+            .inner_hits(InnerHits::new().name("question"));
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "parent_type": "question",
+            "query": {
+                "term": { "tags": { "value": "rust" } }
+            },
+            "score": true,
+            "inner_hits": {
+                "name": "question"
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+}
@@ -0,0 +1,109 @@
+use serde::Serialize;
+use serde_json::Value;
+
+/// Options to retrieve the documents that matched inside a joining query.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct InnerHits {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<u64>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    sort: Vec<Value>,
+
+    #[serde(rename = "_source", skip_serializing_if = "Option::is_none")]
+    source: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    highlight: Option<Value>,
+}
+
+impl InnerHits {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name<T: Into<String>>(self, name: T) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..self
+        }
+    }
+
+    pub fn from<T: Into<u64>>(self, from: T) -> Self {
+        Self {
+            from: Some(from.into()),
+            ..self
+        }
+    }
+
+    pub fn size<T: Into<u64>>(self, size: T) -> Self {
+        Self {
+            size: Some(size.into()),
+            ..self
+        }
+    }
+
+    /// Adds a sort clause, either a field name or a `{ field: options }` object.
+    pub fn sort<T: Into<Value>>(self, sort: T) -> Self {
+        let mut sorts = self.sort;
+        sorts.push(sort.into());
+        Self {
+            sort: sorts,
+            ..self
+        }
+    }
+
+    /// Sets _source field. Accepts a boolean, a list of fields or an includes/excludes object.
+    pub fn source<T: Into<Value>>(self, source: T) -> Self {
+        Self {
+            source: Some(source.into()),
+            ..self
+        }
+    }
+
+    pub fn highlight<T: Into<Value>>(self, highlight: T) -> Self {
+        Self {
+            highlight: Some(highlight.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let inner_hits = InnerHits::new()
+            .name("matched_variants")
+            .from(0_u64)
+            .size(3_u64)
+            .sort(serde_json::json!({ "variants.price": { "order": "asc" } }))
+            .source(vec!["variants.sku", "variants.price"])
+            .highlight(serde_json::json!({ "fields": { "variants.color": {} } }));
+
+        let json = serde_json::to_value(inner_hits).unwrap();
+
+        let expected = serde_json::json!({
+            "name": "matched_variants",
+            "from": 0,
+            "size": 3,
+            "sort": [
+                { "variants.price": { "order": "asc" } }
+            ],
+            "_source": ["variants.sku", "variants.price"],
+            "highlight": {
+                "fields": { "variants.color": {} }
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+}
@@ -0,0 +1,41 @@
+use serde::Serialize;
+
+mod has_child;
+mod has_parent;
+mod inner_hits;
+mod nested;
+mod parent_id;
+
+pub use has_child::HasChild;
+pub use has_parent::HasParent;
+pub use inner_hits::InnerHits;
+pub use nested::Nested;
+pub use parent_id::ParentId;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Joining {
+    Nested(Box<Nested>),
+    HasChild(Box<HasChild>),
+    HasParent(Box<HasParent>),
+    ParentId(Box<ParentId>),
+}
+
+macro_rules! from_types {
+    ($($ty:ident),*) => {
+        $(
+            impl From<$ty> for Joining {
+                fn from(val: $ty) -> Self {
+                    Self::$ty(Box::new(val.into()))
+                }
+            }
+        )*
+    }
+}
+
+from_types! {
+    Nested,
+    HasChild,
+    HasParent,
+    ParentId
+}
@@ -0,0 +1,104 @@
+use super::InnerHits;
+use crate::options::ScoreMode;
+use crate::QueryClause;
+use serde::Serialize;
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct Nested {
+    path: Option<String>,
+    query: Option<QueryClause>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    score_mode: Option<ScoreMode>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_unmapped: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inner_hits: Option<InnerHits>,
+}
+
+impl Nested {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn path<T: Into<String>>(self, path: T) -> Self {
+        Self {
+            path: Some(path.into()),
+            ..self
+        }
+    }
+
+    pub fn query<T: Into<QueryClause>>(self, query: T) -> Self {
+        Self {
+            query: Some(query.into()),
+            ..self
+        }
+    }
+
+    pub fn score_mode<T: Into<ScoreMode>>(self, score_mode: T) -> Self {
+        Self {
+            score_mode: Some(score_mode.into()),
+            ..self
+        }
+    }
+
+    pub fn ignore_unmapped(self, ignore_unmapped: bool) -> Self {
+        Self {
+            ignore_unmapped: Some(ignore_unmapped),
+            ..self
+        }
+    }
+
+    pub fn inner_hits<T: Into<InnerHits>>(self, inner_hits: T) -> Self {
+        Self {
+            inner_hits: Some(inner_hits.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compound::Bool;
+    use crate::term_level::{Range, Term};
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = Nested::new()
+            .path("variants")
+            .query(
+                Bool::new()
+                    .must(Term::new().field("variants.sku").value("SKU-1"))
+                    .filter(Range::new().field("variants.stock").gt(0)),
+            )
+            .score_mode(ScoreMode::Max)
+            .ignore_unmapped(true)
+            .inner_hits(InnerHits::new().size(1_u64));
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "path": "variants",
+            "query": {
+                "bool": {
+                    "must": [
+                        { "term": { "variants.sku": { "value": "SKU-1" } } }
+                    ],
+                    "filter": [
+                        { "range": { "variants.stock": { "gt": 0 } } }
+                    ]
+                }
+            },
+            "score_mode": "max",
+            "ignore_unmapped": true,
+            "inner_hits": {
+                "size": 1
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+}
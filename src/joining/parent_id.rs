@@ -0,0 +1,60 @@
+use serde::Serialize;
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct ParentId {
+    #[serde(rename = "type")]
+    typ: Option<String>,
+
+    id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignore_unmapped: Option<bool>,
+}
+
+impl ParentId {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets type field, the name of the child relation.
+    pub fn typ<T: Into<String>>(self, typ: T) -> Self {
+        Self {
+            typ: Some(typ.into()),
+            ..self
+        }
+    }
+
+    /// Sets id field, the id of the parent document.
+    pub fn id<T: Into<String>>(self, id: T) -> Self {
+        Self {
+            id: Some(id.into()),
+            ..self
+        }
+    }
+
+    pub fn ignore_unmapped(self, ignore_unmapped: bool) -> Self {
+        Self {
+            ignore_unmapped: Some(ignore_unmapped),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = ParentId::new().typ("answer").id("1");
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "type": "answer",
+            "id": "1"
+        });
+
+        assert_eq!(json, expected);
+    }
+}
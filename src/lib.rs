@@ -1,24 +1,81 @@
+// The existing tests pass integer literals as `1 as u64` to the generic setters.
+#![cfg_attr(test, allow(clippy::unnecessary_cast))]
+
 use serde::Serialize;
 
-pub mod options;
+pub mod compound;
 pub mod full_text;
+pub mod geo;
+pub mod joining;
+pub mod options;
+pub mod pipeline;
+mod raw;
+pub mod span;
+pub mod specialized;
 pub mod term_level;
 
-use full_text::FullText;
-use term_level::TermLevel;
+pub use raw::Raw;
 
-#[derive(Debug, Clone)]
-pub struct Query;
+use compound::{Bool, Boosting, Compound, ConstantScore, DisMax, FunctionScore, Hybrid};
+use full_text::{
+    FullText, Intervals, Match, MatchAll, MatchBoolPrefix, MatchNone, MatchPhrase,
+    MatchPhrasePrefix, MultiMatch, Neural, NeuralSparse, QueryString, SimpleQueryString,
+};
+use geo::{Geo, GeoBoundingBox, GeoDistance, GeoPolygon, GeoShape, XyPoint, XyShape};
+use joining::{HasChild, HasParent, Joining, Nested, ParentId};
+use span::{
+    FieldMaskingSpan, Span, SpanContaining, SpanFirst, SpanMulti, SpanNear, SpanNot, SpanOr,
+    SpanTerm, SpanWithin,
+};
+use specialized::{Knn, MoreLikeThis, Percolate, ScriptQuery, ScriptScore, Specialized, Wrapper};
+use term_level::{
+    DistanceFeature, Exists, Fuzzy, Ids, Prefix, Range, RankFeature, Regexp, Term, TermLevel,
+    Terms, TermsSet, Wildcard,
+};
 
-impl Default for Query {
-    fn default() -> Self {
-        Self::new()
-    }
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct Query {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query: Option<RootQuery>,
+}
+
+/// The query of a search body. A hybrid query is only accepted here, never nested.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum RootQuery {
+    Hybrid(Hybrid),
+    #[serde(untagged)]
+    Clause(Box<QueryClause>),
 }
 
 impl Query {
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Build query from any query clause
+    ///
+    /// ```
+    /// use osquery::{term_level::Exists, Query};
+    ///
+    /// let query = Query::new().query(Exists::new().field("speaker"));
+    ///
+    /// let json = serde_json::to_value(query).unwrap();
+    ///
+    /// let expected = serde_json::json!({
+    ///     "query": {
+    ///         "exists": {
+    ///             "field": "speaker"
+    ///         }
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(json, expected);
+    /// ```
+    pub fn query<T: Into<QueryClause>>(self, query: T) -> Self {
+        Self {
+            query: Some(RootQuery::Clause(Box::new(query.into()))),
+        }
     }
 
     /// Build "term_level" query
@@ -47,10 +104,8 @@ impl Query {
     ///
     /// assert_eq!(json, expected);
     /// ```
-    pub fn term_level<T: Into<TermLevel>>(self, query: T) -> TermLevelQuery {
-        TermLevelQuery {
-            query: query.into(),
-        }
+    pub fn term_level<T: Into<TermLevel>>(self, query: T) -> Self {
+        self.query(query.into())
     }
 
     /// Build "full_text" query
@@ -81,19 +136,299 @@ impl Query {
     ///
     /// assert_eq!(json, expected);
     /// ```
-    pub fn full_text<T: Into<FullText>>(self, query: T) -> FullTextQuery {
-        FullTextQuery {
-            query: query.into(),
+    pub fn full_text<T: Into<FullText>>(self, query: T) -> Self {
+        self.query(query.into())
+    }
+
+    /// Build "compound" query
+    ///
+    /// ```
+    /// use osquery::{compound::Bool, full_text::Match, term_level::Term, Query};
+    ///
+    /// let query = Query::new()
+    ///     .compound(
+    ///         Bool::new()
+    ///             .must(Match::new().field("text_entry").value("love"))
+    ///             .filter(Term::new().field("speaker").value("ROMEO"))
+    ///     );
+    ///
+    /// let json = serde_json::to_value(query).unwrap();
+    ///
+    /// let expected = serde_json::json!({
+    ///     "query": {
+    ///         "bool": {
+    ///             "must": [
+    ///                 { "match": { "text_entry": { "query": "love" } } }
+    ///             ],
+    ///             "filter": [
+    ///                 { "term": { "speaker": { "value": "ROMEO" } } }
+    ///             ]
+    ///         }
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(json, expected);
+    /// ```
+    pub fn compound<T: Into<Compound>>(self, query: T) -> Self {
+        self.query(query.into())
+    }
+
+    /// Build "span" query
+    ///
+    /// ```
+    /// use osquery::{span::{SpanNear, SpanTerm}, Query};
+    ///
+    /// let query = Query::new()
+    ///     .span(
+    ///         SpanNear::new()
+    ///             .clause(SpanTerm::new().field("text_entry").value("to"))
+    ///             .clause(SpanTerm::new().field("text_entry").value("be"))
+    ///             .slop(0_u64)
+    ///             .in_order(true)
+    ///     );
+    ///
+    /// let json = serde_json::to_value(query).unwrap();
+    ///
+    /// let expected = serde_json::json!({
+    ///     "query": {
+    ///         "span_near": {
+    ///             "clauses": [
+    ///                 { "span_term": { "text_entry": { "value": "to" } } },
+    ///                 { "span_term": { "text_entry": { "value": "be" } } }
+    ///             ],
+    ///             "slop": 0,
+    ///             "in_order": true
+    ///         }
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(json, expected);
+    /// ```
+    pub fn span<T: Into<Span>>(self, query: T) -> Self {
+        self.query(query.into())
+    }
+
+    /// Build "geo" query
+    ///
+    /// ```
+    /// use osquery::{geo::{Distance, GeoDistance, GeoPoint}, options::DistanceUnit, Query};
+    ///
+    /// let query = Query::new()
+    ///     .geo(
+    ///         GeoDistance::new()
+    ///             .field("location")
+    ///             .point(GeoPoint::lat_lon(40.71, -74.0))
+    ///             .distance(Distance::new(5, DistanceUnit::Kilometers))
+    ///     );
+    ///
+    /// let json = serde_json::to_value(query).unwrap();
+    ///
+    /// let expected = serde_json::json!({
+    ///     "query": {
+    ///         "geo_distance": {
+    ///             "distance": "5km",
+    ///             "location": {
+    ///                 "lat": 40.71,
+    ///                 "lon": -74.0
+    ///             }
+    ///         }
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(json, expected);
+    /// ```
+    pub fn geo<T: Into<Geo>>(self, query: T) -> Self {
+        self.query(query.into())
+    }
+
+    /// Build "joining" query
+    ///
+    /// ```
+    /// use osquery::{joining::Nested, term_level::Term, Query};
+    ///
+    /// let query = Query::new()
+    ///     .joining(
+    ///         Nested::new()
+    ///             .path("variants")
+    ///             .query(Term::new().field("variants.sku").value("SKU-1"))
+    ///     );
+    ///
+    /// let json = serde_json::to_value(query).unwrap();
+    ///
+    /// let expected = serde_json::json!({
+    ///     "query": {
+    ///         "nested": {
+    ///             "path": "variants",
+    ///             "query": {
+    ///                 "term": {
+    ///                     "variants.sku": {
+    ///                         "value": "SKU-1"
+    ///                     }
+    ///                 }
+    ///             }
+    ///         }
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(json, expected);
+    /// ```
+    pub fn joining<T: Into<Joining>>(self, query: T) -> Self {
+        self.query(query.into())
+    }
+
+    /// Build "hybrid" query, which is only valid as the top-level query
+    ///
+    /// ```
+    /// use osquery::{compound::Hybrid, full_text::{Match, Neural}, Query};
+    ///
+    /// let query = Query::new()
+    ///     .hybrid(
+    ///         Hybrid::new()
+    ///             .query(Match::new().field("text").value("cowboy rodeo bronco"))
+    ///             .query(
+    ///                 Neural::new()
+    ///                     .field("passage_embedding")
+    ///                     .query_text("wild west")
+    ///                     .model_id("aVeif4oB5Vm0Tdw8zYO2")
+    ///                     .k(5_u64)
+    ///             )
+    ///     );
+    ///
+    /// let json = serde_json::to_value(query).unwrap();
+    ///
+    /// let expected = serde_json::json!({
+    ///     "query": {
+    ///         "hybrid": {
+    ///             "queries": [
+    ///                 { "match": { "text": { "query": "cowboy rodeo bronco" } } },
+    ///                 {
+    ///                     "neural": {
+    ///                         "passage_embedding": {
+    ///                             "query_text": "wild west",
+    ///                             "model_id": "aVeif4oB5Vm0Tdw8zYO2",
+    ///                             "k": 5
+    ///                         }
+    ///                     }
+    ///                 }
+    ///             ]
+    ///         }
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(json, expected);
+    /// ```
+    pub fn hybrid<T: Into<Hybrid>>(self, query: T) -> Self {
+        Self {
+            query: Some(RootQuery::Hybrid(query.into())),
         }
     }
+
+    /// Build "specialized" query
+    ///
+    /// ```
+    /// use osquery::{options::Script, specialized::ScriptQuery, Query};
+    ///
+    /// let query = Query::new()
+    ///     .specialized(
+    ///         ScriptQuery::new()
+    ///             .script(Script::new().source("doc['line_id'].value > 100"))
+    ///     );
+    ///
+    /// let json = serde_json::to_value(query).unwrap();
+    ///
+    /// let expected = serde_json::json!({
+    ///     "query": {
+    ///         "script": {
+    ///             "script": {
+    ///                 "source": "doc['line_id'].value > 100"
+    ///             }
+    ///         }
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(json, expected);
+    /// ```
+    pub fn specialized<T: Into<Specialized>>(self, query: T) -> Self {
+        self.query(query.into())
+    }
 }
 
+/// A single query of any family, which can be nested inside compound queries.
 #[derive(Debug, Clone, Serialize)]
-pub struct TermLevelQuery {
-    query: TermLevel,
+#[serde(untagged)]
+pub enum QueryClause {
+    TermLevel(TermLevel),
+    FullText(FullText),
+    Compound(Compound),
+    Geo(Geo),
+    Joining(Joining),
+    Span(Span),
+    Specialized(Specialized),
+    Raw(Raw),
 }
 
-#[derive(Debug, Clone, Serialize)]
-pub struct FullTextQuery {
-    query: FullText,
+macro_rules! from_families {
+    ($($ty:ident),*) => {
+        $(
+            impl From<$ty> for QueryClause {
+                fn from(val: $ty) -> Self {
+                    Self::$ty(val)
+                }
+            }
+        )*
+    }
+}
+
+from_families! {
+    TermLevel,
+    FullText,
+    Compound,
+    Geo,
+    Joining,
+    Span,
+    Specialized,
+    Raw
+}
+
+macro_rules! from_types {
+    ($family:ident: $($ty:ident),*) => {
+        $(
+            impl From<$ty> for QueryClause {
+                fn from(val: $ty) -> Self {
+                    Self::$family(val.into())
+                }
+            }
+        )*
+    }
+}
+
+from_types! {
+    TermLevel: Term, Terms, TermsSet, Ids, Range, Prefix, Exists, Fuzzy, Wildcard, Regexp,
+    RankFeature, DistanceFeature
+}
+
+from_types! {
+    FullText: Match, MultiMatch, MatchBoolPrefix, MatchPhrase, MatchPhrasePrefix, QueryString,
+    SimpleQueryString, MatchAll, MatchNone, Intervals, Neural, NeuralSparse
+}
+
+from_types! {
+    Compound: Bool, Boosting, ConstantScore, DisMax, FunctionScore
+}
+
+from_types! {
+    Geo: GeoDistance, GeoBoundingBox, GeoPolygon, GeoShape, XyShape, XyPoint
+}
+
+from_types! {
+    Joining: Nested, HasChild, HasParent, ParentId
+}
+
+from_types! {
+    Span: SpanTerm, SpanNear, SpanOr, SpanNot, SpanFirst, SpanContaining, SpanWithin,
+    FieldMaskingSpan, SpanMulti
+}
+
+from_types! {
+    Specialized: ScriptQuery, ScriptScore, MoreLikeThis, Percolate, Knn, Wrapper
 }
@@ -1,4 +1,8 @@
 use serde::ser::{Serialize, Serializer};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
 
 #[derive(Debug, Clone)]
 pub enum Fuzziness {
@@ -24,6 +28,58 @@ impl Serialize for Fuzziness {
     }
 }
 
+/// A minimum_should_match value: a count of clauses, which may be negative, or a
+/// percentage or combination in text form like `75%` or `3<90%`.
+#[derive(Debug, Clone)]
+pub enum MinimumShouldMatch {
+    Count(i64),
+    Text(String),
+}
+
+macro_rules! minimum_should_match_from_ints {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for MinimumShouldMatch {
+                fn from(val: $ty) -> Self {
+                    Self::Count(val.into())
+                }
+            }
+        )*
+    }
+}
+
+minimum_should_match_from_ints! { i32, i64, u8, u16, u32 }
+
+impl From<u64> for MinimumShouldMatch {
+    fn from(val: u64) -> Self {
+        Self::Count(i64::try_from(val).unwrap_or(i64::MAX))
+    }
+}
+
+impl From<&str> for MinimumShouldMatch {
+    fn from(val: &str) -> Self {
+        Self::Text(val.into())
+    }
+}
+
+impl From<String> for MinimumShouldMatch {
+    fn from(val: String) -> Self {
+        Self::Text(val)
+    }
+}
+
+impl Serialize for MinimumShouldMatch {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            MinimumShouldMatch::Count(count) => serializer.serialize_i64(*count),
+            MinimumShouldMatch::Text(text) => serializer.serialize_str(text),
+        }
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Operator {
@@ -62,6 +118,466 @@ pub enum ZeroTermsQuery {
     All,
 }
 
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FunctionScoreMode {
+    Multiply,
+    Sum,
+    Avg,
+    First,
+    Max,
+    Min,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BoostMode {
+    Multiply,
+    Replace,
+    Sum,
+    Avg,
+    Max,
+    Min,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldValueFactorModifier {
+    None,
+    Log,
+    Log1p,
+    Log2p,
+    Ln,
+    Ln1p,
+    Ln2p,
+    Square,
+    Sqrt,
+    Reciprocal,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MultiValueMode {
+    Min,
+    Max,
+    Avg,
+    Sum,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScoreMode {
+    Avg,
+    Max,
+    Min,
+    None,
+    Sum,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum DistanceUnit {
+    Miles,
+    Yards,
+    Feet,
+    Inches,
+    Kilometers,
+    Meters,
+    Centimeters,
+    Millimeters,
+    NauticalMiles,
+}
+
+impl DistanceUnit {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            DistanceUnit::Miles => "mi",
+            DistanceUnit::Yards => "yd",
+            DistanceUnit::Feet => "ft",
+            DistanceUnit::Inches => "in",
+            DistanceUnit::Kilometers => "km",
+            DistanceUnit::Meters => "m",
+            DistanceUnit::Centimeters => "cm",
+            DistanceUnit::Millimeters => "mm",
+            DistanceUnit::NauticalMiles => "nmi",
+        }
+    }
+}
+
+impl Serialize for DistanceUnit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DistanceType {
+    Arc,
+    Plane,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ValidationMethod {
+    Strict,
+    IgnoreMalformed,
+    Coerce,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpatialRelation {
+    Intersects,
+    Disjoint,
+    Within,
+    Contains,
+}
+
+/// Spatial relations available to queries on point fields.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PointRelation {
+    Intersects,
+    Disjoint,
+    Within,
+}
+
+/// Relations between the query range and the values of a range field.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RangeRelation {
+    Intersects,
+    Contains,
+    Within,
+}
+
+/// How many results a vector search returns: the k nearest neighbors, or every document
+/// within a min_score or max_distance threshold for a radial search. Only one is allowed.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum VectorLimit {
+    K(u64),
+    MinScore(f64),
+    MaxDistance(f64),
+}
+
+/// Score normalization techniques of the normalization-processor.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NormalizationTechnique {
+    MinMax,
+    L2,
+}
+
+/// Score combination techniques of the normalization-processor.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CombinationTechnique {
+    ArithmeticMean,
+    GeometricMean,
+    HarmonicMean,
+}
+
+/// A script given either inline by its source or by the id of a stored script.
+#[derive(Debug, Default, Clone, serde::Serialize)]
+pub struct Script {
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    code: Option<ScriptCode>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lang: Option<String>,
+
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    params: BTreeMap<String, Value>,
+}
+
+/// Inline source or stored script id; setting one replaces the other.
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ScriptCode {
+    Source(String),
+    Id(String),
+}
+
+impl Script {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets source field for an inline script.
+    pub fn source<T: Into<String>>(self, source: T) -> Self {
+        Self {
+            code: Some(ScriptCode::Source(source.into())),
+            ..self
+        }
+    }
+
+    /// Sets id field for a stored script.
+    pub fn id<T: Into<String>>(self, id: T) -> Self {
+        Self {
+            code: Some(ScriptCode::Id(id.into())),
+            ..self
+        }
+    }
+
+    pub fn lang<T: Into<String>>(self, lang: T) -> Self {
+        Self {
+            lang: Some(lang.into()),
+            ..self
+        }
+    }
+
+    pub fn params<P, K, V>(self, params: P) -> Self
+    where
+        P: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<Value>,
+    {
+        Self {
+            params: params
+                .into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+            ..self
+        }
+    }
+
+    pub fn param<K: Into<String>, V: Into<Value>>(self, key: K, value: V) -> Self {
+        let mut params = self.params;
+        params.insert(key.into(), value.into());
+        Self { params, ..self }
+    }
+}
+
+impl From<&str> for Script {
+    fn from(source: &str) -> Self {
+        Self::new().source(source)
+    }
+}
+
+impl From<String> for Script {
+    fn from(source: String) -> Self {
+        Self::new().source(source)
+    }
+}
+
+/// Time units of date math expressions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateMathUnit {
+    Years,
+    Months,
+    Weeks,
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+}
+
+impl DateMathUnit {
+    fn as_char(&self) -> char {
+        match self {
+            DateMathUnit::Years => 'y',
+            DateMathUnit::Months => 'M',
+            DateMathUnit::Weeks => 'w',
+            DateMathUnit::Days => 'd',
+            DateMathUnit::Hours => 'h',
+            DateMathUnit::Minutes => 'm',
+            DateMathUnit::Seconds => 's',
+        }
+    }
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'y' => Some(DateMathUnit::Years),
+            'M' => Some(DateMathUnit::Months),
+            'w' => Some(DateMathUnit::Weeks),
+            'd' => Some(DateMathUnit::Days),
+            'h' | 'H' => Some(DateMathUnit::Hours),
+            'm' => Some(DateMathUnit::Minutes),
+            's' => Some(DateMathUnit::Seconds),
+            _ => None,
+        }
+    }
+}
+
+/// A date math expression such as `now-1d/d` or `2024-01-01||+1M/M`.
+///
+/// Build one with [`DateMath::now`] or [`DateMath::date`], or validate an existing
+/// expression by parsing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateMath {
+    anchor: Option<String>,
+    ops: Vec<DateMathOp>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DateMathOp {
+    Add(u64, DateMathUnit),
+    Subtract(u64, DateMathUnit),
+    Round(DateMathUnit),
+}
+
+impl DateMath {
+    /// Starts an expression anchored at the current time.
+    pub fn now() -> Self {
+        Self {
+            anchor: None,
+            ops: Vec::new(),
+        }
+    }
+
+    /// Starts an expression anchored at the given date, which should be non-empty and must not
+    /// contain `||`. The date is passed through as is: its format is not checked, OpenSearch
+    /// parses it with the format of the field. Parse the whole expression to validate it.
+    pub fn date<T: Into<String>>(date: T) -> Self {
+        Self {
+            anchor: Some(date.into()),
+            ops: Vec::new(),
+        }
+    }
+
+    pub fn add(self, amount: u64, unit: DateMathUnit) -> Self {
+        self.push(DateMathOp::Add(amount, unit))
+    }
+
+    pub fn subtract(self, amount: u64, unit: DateMathUnit) -> Self {
+        self.push(DateMathOp::Subtract(amount, unit))
+    }
+
+    /// Rounds down to the start of the given unit.
+    pub fn round(self, unit: DateMathUnit) -> Self {
+        self.push(DateMathOp::Round(unit))
+    }
+
+    fn push(self, op: DateMathOp) -> Self {
+        let mut ops = self.ops;
+        ops.push(op);
+        Self { ops, ..self }
+    }
+}
+
+impl fmt::Display for DateMath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.anchor.as_deref() {
+            None => write!(f, "now")?,
+            Some(date) if self.ops.is_empty() => write!(f, "{date}")?,
+            Some(date) => write!(f, "{date}||")?,
+        }
+        for op in self.ops.iter() {
+            match op {
+                DateMathOp::Add(amount, unit) => write!(f, "+{amount}{}", unit.as_char())?,
+                DateMathOp::Subtract(amount, unit) => write!(f, "-{amount}{}", unit.as_char())?,
+                DateMathOp::Round(unit) => write!(f, "/{}", unit.as_char())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The error returned when parsing an invalid date math expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateMathError(String);
+
+impl fmt::Display for DateMathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid date math expression: {}", self.0)
+    }
+}
+
+impl std::error::Error for DateMathError {}
+
+impl FromStr for DateMath {
+    type Err = DateMathError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = |reason: &str| DateMathError(format!("{s:?}, {reason}"));
+
+        let (mut date, math) = if let Some(math) = s.strip_prefix("now") {
+            (DateMath::now(), math)
+        } else {
+            let (anchor, math) = s.split_once("||").unwrap_or((s, ""));
+            if anchor.is_empty() {
+                return Err(err("missing anchor date"));
+            }
+            if math.contains("||") {
+                return Err(err("more than one `||`"));
+            }
+            if ends_with_date_math(anchor) {
+                return Err(err(
+                    "date math after a date must follow `||`, like `2024-01-01||+1d`",
+                ));
+            }
+            (DateMath::date(anchor), math)
+        };
+
+        let mut chars = math.chars().peekable();
+        while let Some(c) = chars.next() {
+            let op = match c {
+                '+' | '-' => {
+                    let mut digits = String::new();
+                    while let Some(d) = chars.next_if(char::is_ascii_digit) {
+                        digits.push(d);
+                    }
+                    let amount = digits
+                        .parse::<u64>()
+                        .map_err(|_| err("expected an amount after `+` or `-`"))?;
+                    let unit = chars
+                        .next()
+                        .and_then(DateMathUnit::from_char)
+                        .ok_or_else(|| err("expected a time unit"))?;
+                    if c == '+' {
+                        DateMathOp::Add(amount, unit)
+                    } else {
+                        DateMathOp::Subtract(amount, unit)
+                    }
+                }
+                '/' => {
+                    let unit = chars
+                        .next()
+                        .and_then(DateMathUnit::from_char)
+                        .ok_or_else(|| err("expected a time unit after `/`"))?;
+                    DateMathOp::Round(unit)
+                }
+                _ => return Err(err("expected `+`, `-` or `/`")),
+            };
+            date = date.push(op);
+        }
+
+        Ok(date)
+    }
+}
+
+/// Whether `anchor` ends with an operation like `+1d` or `/d`, which is a date math
+/// expression missing its `||` separator.
+fn ends_with_date_math(anchor: &str) -> bool {
+    let Some(rest) = anchor.strip_suffix(|c| DateMathUnit::from_char(c).is_some()) else {
+        return false;
+    };
+    if rest.ends_with('/') {
+        return true;
+    }
+    let without_amount = rest.trim_end_matches(|c: char| c.is_ascii_digit());
+    without_amount.len() < rest.len() && without_amount.ends_with(['+', '-'])
+}
+
+impl Serialize for DateMath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl From<DateMath> for Value {
+    fn from(date: DateMath) -> Self {
+        Value::String(date.to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -269,4 +785,401 @@ mod tests {
         });
         assert_eq!(json, expected);
     }
+
+    #[test]
+    fn it_serializes_function_score_mode_multiply() {
+        let json = serde_json::to_value(serde_json::json!({
+            "value": FunctionScoreMode::Multiply,
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "value": "multiply"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_function_score_mode_first() {
+        let json = serde_json::to_value(serde_json::json!({
+            "value": FunctionScoreMode::First,
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "value": "first"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_boost_mode_replace() {
+        let json = serde_json::to_value(serde_json::json!({
+            "value": BoostMode::Replace,
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "value": "replace"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_boost_mode_avg() {
+        let json = serde_json::to_value(serde_json::json!({
+            "value": BoostMode::Avg,
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "value": "avg"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_field_value_factor_modifier_none() {
+        let json = serde_json::to_value(serde_json::json!({
+            "value": FieldValueFactorModifier::None,
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "value": "none"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_field_value_factor_modifier_log1p() {
+        let json = serde_json::to_value(serde_json::json!({
+            "value": FieldValueFactorModifier::Log1p,
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "value": "log1p"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_field_value_factor_modifier_ln2p() {
+        let json = serde_json::to_value(serde_json::json!({
+            "value": FieldValueFactorModifier::Ln2p,
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "value": "ln2p"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_field_value_factor_modifier_reciprocal() {
+        let json = serde_json::to_value(serde_json::json!({
+            "value": FieldValueFactorModifier::Reciprocal,
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "value": "reciprocal"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_multi_value_mode_sum() {
+        let json = serde_json::to_value(serde_json::json!({
+            "value": MultiValueMode::Sum,
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "value": "sum"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_score_mode_avg() {
+        let json = serde_json::to_value(serde_json::json!({
+            "value": ScoreMode::Avg,
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "value": "avg"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_score_mode_none() {
+        let json = serde_json::to_value(serde_json::json!({
+            "value": ScoreMode::None,
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "value": "none"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_distance_unit_kilometers() {
+        let json = serde_json::to_value(serde_json::json!({
+            "value": DistanceUnit::Kilometers,
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "value": "km"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_distance_unit_nautical_miles() {
+        let json = serde_json::to_value(serde_json::json!({
+            "value": DistanceUnit::NauticalMiles,
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "value": "nmi"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_distance_type_arc() {
+        let json = serde_json::to_value(serde_json::json!({
+            "value": DistanceType::Arc,
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "value": "arc"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_validation_method_ignore_malformed() {
+        let json = serde_json::to_value(serde_json::json!({
+            "value": ValidationMethod::IgnoreMalformed,
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "value": "IGNORE_MALFORMED"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_spatial_relation_intersects() {
+        let json = serde_json::to_value(serde_json::json!({
+            "value": SpatialRelation::Intersects,
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "value": "intersects"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_spatial_relation_within() {
+        let json = serde_json::to_value(serde_json::json!({
+            "value": SpatialRelation::Within,
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "value": "within"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_point_relation_disjoint() {
+        let json = serde_json::to_value(serde_json::json!({
+            "value": PointRelation::Disjoint,
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "value": "disjoint"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_normalization_technique_min_max() {
+        let json = serde_json::to_value(serde_json::json!({
+            "value": NormalizationTechnique::MinMax,
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "value": "min_max"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_combination_technique_harmonic_mean() {
+        let json = serde_json::to_value(serde_json::json!({
+            "value": CombinationTechnique::HarmonicMean,
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "value": "harmonic_mean"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_range_relation_intersects() {
+        let json = serde_json::to_value(serde_json::json!({
+            "value": RangeRelation::Intersects,
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "value": "INTERSECTS"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_minimum_should_match() {
+        let json = serde_json::to_value(serde_json::json!({
+            "count": MinimumShouldMatch::from(2_u64),
+            "negative": MinimumShouldMatch::from(-1),
+            "percentage": MinimumShouldMatch::from("75%"),
+            "combination": MinimumShouldMatch::from("3<90%"),
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "count": 2,
+            "negative": -1,
+            "percentage": "75%",
+            "combination": "3<90%"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_inline_script() {
+        let json = serde_json::to_value(
+            Script::new()
+                .source("doc['likes'].value * params.factor")
+                .lang("painless")
+                .param("factor", 1.5),
+        )
+        .unwrap();
+        let expected = serde_json::json!({
+            "source": "doc['likes'].value * params.factor",
+            "lang": "painless",
+            "params": {
+                "factor": 1.5
+            }
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_stored_script() {
+        let json = serde_json::to_value(
+            Script::new()
+                .id("calculate-score")
+                .params(vec![("my_modifier", 2)]),
+        )
+        .unwrap();
+        let expected = serde_json::json!({
+            "id": "calculate-score",
+            "params": {
+                "my_modifier": 2
+            }
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_converts_str_into_inline_script() {
+        let json = serde_json::to_value(Script::from("_score * 2")).unwrap();
+        let expected = serde_json::json!({
+            "source": "_score * 2"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_keeps_only_the_last_script_code() {
+        let json =
+            serde_json::to_value(Script::new().source("_score * 2").id("calculate-score")).unwrap();
+        let expected = serde_json::json!({
+            "id": "calculate-score"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_script_params_in_key_order() {
+        let script = Script::new()
+            .source("params.b + params.a")
+            .param("b", 2)
+            .param("a", 1);
+        let json = serde_json::to_string(&script).unwrap();
+        assert_eq!(
+            json,
+            r#"{"source":"params.b + params.a","params":{"a":1,"b":2}}"#
+        );
+    }
+
+    #[test]
+    fn it_serializes_date_math() {
+        let json = serde_json::to_value(serde_json::json!({
+            "now": DateMath::now().subtract(1, DateMathUnit::Days).round(DateMathUnit::Days),
+            "date": DateMath::date("2024-01-01").add(1, DateMathUnit::Months).round(DateMathUnit::Months),
+            "plain": DateMath::date("2024-01-01"),
+        }))
+        .unwrap();
+        let expected = serde_json::json!({
+            "now": "now-1d/d",
+            "date": "2024-01-01||+1M/M",
+            "plain": "2024-01-01"
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_parses_date_math() {
+        for expr in [
+            "now",
+            "now-1d/d",
+            "now+12h-30m/m",
+            "2024-01-01||+1M/M",
+            "2024-01-01",
+            "2024-01-01T10:00:00+01:00",
+        ] {
+            let date: DateMath = expr.parse().unwrap();
+            assert_eq!(date.to_string(), expr);
+        }
+        assert_eq!(
+            "now-1H".parse::<DateMath>().unwrap(),
+            DateMath::now().subtract(1, DateMathUnit::Hours)
+        );
+    }
+
+    #[test]
+    fn it_rejects_invalid_date_math() {
+        for expr in [
+            "",
+            "now-d",
+            "now-1x",
+            "now/",
+            "now*2d",
+            "||+1d",
+            "2024||+1d||-1d",
+            "2024-01-01+1d",
+            "2024-01-01-12h",
+            "2024-01-01/M",
+        ] {
+            assert!(
+                expr.parse::<DateMath>().is_err(),
+                "{expr} should be rejected"
+            );
+        }
+
+        let err = "2024-01-01+1d".parse::<DateMath>().unwrap_err();
+        assert!(err.to_string().contains("`||`"), "{err}");
+    }
 }
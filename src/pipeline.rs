@@ -0,0 +1,182 @@
+use crate::options::{CombinationTechnique, NormalizationTechnique};
+use serde::Serialize;
+
+/// Body of a search pipeline definition, as sent to `PUT /_search/pipeline/<name>`.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct SearchPipeline {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    phase_results_processors: Vec<PhaseResultsProcessor>,
+}
+
+impl SearchPipeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn description<T: Into<String>>(self, description: T) -> Self {
+        Self {
+            description: Some(description.into()),
+            ..self
+        }
+    }
+
+    pub fn phase_results_processor<T: Into<PhaseResultsProcessor>>(self, processor: T) -> Self {
+        let mut phase_results_processors = self.phase_results_processors;
+        phase_results_processors.push(processor.into());
+        Self {
+            phase_results_processors,
+            ..self
+        }
+    }
+}
+
+/// Processors run between the query and fetch phases of a search.
+#[derive(Debug, Clone, Serialize)]
+pub enum PhaseResultsProcessor {
+    #[serde(rename = "normalization-processor")]
+    Normalization(NormalizationProcessor),
+}
+
+impl From<NormalizationProcessor> for PhaseResultsProcessor {
+    fn from(val: NormalizationProcessor) -> Self {
+        Self::Normalization(val)
+    }
+}
+
+/// Normalizes and combines the scores of the sub-queries of a hybrid query.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct NormalizationProcessor {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    normalization: Option<Normalization>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    combination: Option<Combination>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct Normalization {
+    technique: NormalizationTechnique,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+struct Combination {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    technique: Option<CombinationTechnique>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parameters: Option<CombinationParameters>,
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+struct CombinationParameters {
+    weights: Vec<f64>,
+}
+
+impl NormalizationProcessor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn normalization<T: Into<NormalizationTechnique>>(self, technique: T) -> Self {
+        Self {
+            normalization: Some(Normalization {
+                technique: technique.into(),
+            }),
+            ..self
+        }
+    }
+
+    pub fn combination<T: Into<CombinationTechnique>>(self, technique: T) -> Self {
+        let combination = Combination {
+            technique: Some(technique.into()),
+            ..self.combination.unwrap_or_default()
+        };
+        Self {
+            combination: Some(combination),
+            ..self
+        }
+    }
+
+    /// Sets the weights of the sub-queries, in the order of the hybrid query clauses.
+    pub fn weights<W, T>(self, weights: W) -> Self
+    where
+        W: IntoIterator<Item = T>,
+        T: Into<f64>,
+    {
+        let combination = Combination {
+            parameters: Some(CombinationParameters {
+                weights: weights.into_iter().map(|w| w.into()).collect(),
+            }),
+            ..self.combination.unwrap_or_default()
+        };
+        Self {
+            combination: Some(combination),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let pipeline = SearchPipeline::new()
+            .description("Post processor for hybrid search")
+            .phase_results_processor(
+                NormalizationProcessor::new()
+                    .normalization(NormalizationTechnique::MinMax)
+                    .combination(CombinationTechnique::ArithmeticMean)
+                    .weights(vec![0.3, 0.7]),
+            );
+
+        let json = serde_json::to_value(pipeline).unwrap();
+
+        let expected = serde_json::json!({
+            "description": "Post processor for hybrid search",
+            "phase_results_processors": [
+                {
+                    "normalization-processor": {
+                        "normalization": {
+                            "technique": "min_max"
+                        },
+                        "combination": {
+                            "technique": "arithmetic_mean",
+                            "parameters": {
+                                "weights": [0.3, 0.7]
+                            }
+                        }
+                    }
+                }
+            ]
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_weights_only_to_json() {
+        let processor = NormalizationProcessor::new()
+            .normalization(NormalizationTechnique::L2)
+            .weights(vec![0.5, 0.5]);
+
+        let json = serde_json::to_value(processor).unwrap();
+
+        let expected = serde_json::json!({
+            "normalization": {
+                "technique": "l2"
+            },
+            "combination": {
+                "parameters": {
+                    "weights": [0.5, 0.5]
+                }
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+}
@@ -0,0 +1,69 @@
+use serde::Serialize;
+use serde_json::Value;
+
+/// A query clause given as JSON, serialized verbatim. Use it for queries the crate doesn't
+/// support yet; it nests anywhere a typed query clause does.
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct Raw(Value);
+
+impl Raw {
+    pub fn new<T: Into<Value>>(value: T) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<Value> for Raw {
+    fn from(value: Value) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{compound::Bool, term_level::Term, Query};
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = Raw::new(serde_json::json!({
+            "new_query": { "field": "value" }
+        }));
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "new_query": { "field": "value" }
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_nests_inside_typed_queries() {
+        let query = Query::new().query(
+            Bool::new()
+                .must(Term::new().field("status").value("active"))
+                .filter(Raw::new(serde_json::json!({
+                    "new_query": { "field": "value" }
+                }))),
+        );
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "query": {
+                "bool": {
+                    "must": [
+                        { "term": { "status": { "value": "active" } } }
+                    ],
+                    "filter": [
+                        { "new_query": { "field": "value" } }
+                    ]
+                }
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+}
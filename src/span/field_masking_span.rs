@@ -0,0 +1,66 @@
+use super::Span;
+use serde::Serialize;
+
+/// Lets span queries combine spans of different fields by masking the field of the inner span.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct FieldMaskingSpan {
+    query: Option<Span>,
+    field: Option<String>,
+}
+
+impl FieldMaskingSpan {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn query<T: Into<Span>>(self, query: T) -> Self {
+        Self {
+            query: Some(query.into()),
+            ..self
+        }
+    }
+
+    pub fn field<T: Into<String>>(self, field: T) -> Self {
+        Self {
+            field: Some(field.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::span::{SpanNear, SpanTerm};
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = SpanNear::new()
+            .clause(SpanTerm::new().field("text").value("quick"))
+            .clause(
+                FieldMaskingSpan::new()
+                    .query(SpanTerm::new().field("text.stems").value("fox"))
+                    .field("text"),
+            )
+            .slop(5_u64);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "clauses": [
+                { "span_term": { "text": { "value": "quick" } } },
+                {
+                    "field_masking_span": {
+                        "query": {
+                            "span_term": { "text.stems": { "value": "fox" } }
+                        },
+                        "field": "text"
+                    }
+                }
+            ],
+            "slop": 5
+        });
+
+        assert_eq!(json, expected);
+    }
+}
@@ -0,0 +1,59 @@
+use serde::Serialize;
+
+mod field_masking_span;
+mod span_containing;
+mod span_first;
+mod span_multi;
+mod span_near;
+mod span_not;
+mod span_or;
+mod span_term;
+mod span_within;
+
+pub use field_masking_span::FieldMaskingSpan;
+pub use span_containing::SpanContaining;
+pub use span_first::SpanFirst;
+pub use span_multi::{MultiTermQuery, SpanMulti};
+pub use span_near::SpanNear;
+pub use span_not::SpanNot;
+pub use span_or::SpanOr;
+pub use span_term::SpanTerm;
+pub use span_within::SpanWithin;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Span {
+    SpanTerm(Box<SpanTerm>),
+    SpanNear(Box<SpanNear>),
+    SpanOr(Box<SpanOr>),
+    SpanNot(Box<SpanNot>),
+    SpanFirst(Box<SpanFirst>),
+    SpanContaining(Box<SpanContaining>),
+    SpanWithin(Box<SpanWithin>),
+    FieldMaskingSpan(Box<FieldMaskingSpan>),
+    SpanMulti(Box<SpanMulti>),
+}
+
+macro_rules! from_types {
+    ($($ty:ident),*) => {
+        $(
+            impl From<$ty> for Span {
+                fn from(val: $ty) -> Self {
+                    Self::$ty(Box::new(val.into()))
+                }
+            }
+        )*
+    }
+}
+
+from_types! {
+    SpanTerm,
+    SpanNear,
+    SpanOr,
+    SpanNot,
+    SpanFirst,
+    SpanContaining,
+    SpanWithin,
+    FieldMaskingSpan,
+    SpanMulti
+}
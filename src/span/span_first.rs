@@ -0,0 +1,56 @@
+use super::Span;
+use serde::Serialize;
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct SpanFirst {
+    #[serde(rename = "match")]
+    mtch: Option<Span>,
+
+    end: Option<u64>,
+}
+
+impl SpanFirst {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets match field.
+    pub fn mtch<T: Into<Span>>(self, span: T) -> Self {
+        Self {
+            mtch: Some(span.into()),
+            ..self
+        }
+    }
+
+    /// Sets end field, the maximum end position of the match span.
+    pub fn end<T: Into<u64>>(self, end: T) -> Self {
+        Self {
+            end: Some(end.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::span::SpanTerm;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = SpanFirst::new()
+            .mtch(SpanTerm::new().field("text_entry").value("whereas"))
+            .end(3_u64);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "match": {
+                "span_term": { "text_entry": { "value": "whereas" } }
+            },
+            "end": 3
+        });
+
+        assert_eq!(json, expected);
+    }
+}
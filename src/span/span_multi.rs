@@ -0,0 +1,88 @@
+use crate::term_level::{Fuzzy, Prefix, Range, Regexp, Wildcard};
+use serde::Serialize;
+
+/// Wraps a multi-term query so it can be used as a span.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct SpanMulti {
+    #[serde(rename = "match")]
+    mtch: Option<MultiTermQuery>,
+}
+
+/// The term-level queries accepted by span_multi.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MultiTermQuery {
+    Prefix(Prefix),
+    Wildcard(Wildcard),
+    Fuzzy(Fuzzy),
+    Regexp(Regexp),
+    Range(Range),
+}
+
+macro_rules! from_types {
+    ($($ty:ident),*) => {
+        $(
+            impl From<$ty> for MultiTermQuery {
+                fn from(val: $ty) -> Self {
+                    Self::$ty(val)
+                }
+            }
+        )*
+    }
+}
+
+from_types! {
+    Prefix,
+    Wildcard,
+    Fuzzy,
+    Regexp,
+    Range
+}
+
+impl SpanMulti {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets match field.
+    pub fn mtch<T: Into<MultiTermQuery>>(self, query: T) -> Self {
+        Self {
+            mtch: Some(query.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_serializes_prefix_to_json() {
+        let query = SpanMulti::new().mtch(Prefix::new().field("text_entry").value("lov"));
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "match": {
+                "prefix": { "text_entry": "lov" }
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_range_to_json() {
+        let query = SpanMulti::new().mtch(Range::new().field("line_id").gte(10).lt(20));
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "match": {
+                "range": { "line_id": { "gte": 10, "lt": 20 } }
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+}
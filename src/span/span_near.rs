@@ -0,0 +1,78 @@
+use super::Span;
+use serde::Serialize;
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct SpanNear {
+    clauses: Vec<Span>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slop: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    in_order: Option<bool>,
+}
+
+impl SpanNear {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn clauses<C, T>(self, clauses: C) -> Self
+    where
+        C: IntoIterator<Item = T>,
+        T: Into<Span>,
+    {
+        Self {
+            clauses: clauses.into_iter().map(|c| c.into()).collect(),
+            ..self
+        }
+    }
+
+    pub fn clause<T: Into<Span>>(self, clause: T) -> Self {
+        let mut clauses = self.clauses;
+        clauses.push(clause.into());
+        Self { clauses, ..self }
+    }
+
+    pub fn slop<T: Into<u64>>(self, slop: T) -> Self {
+        Self {
+            slop: Some(slop.into()),
+            ..self
+        }
+    }
+
+    pub fn in_order(self, in_order: bool) -> Self {
+        Self {
+            in_order: Some(in_order),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::span::SpanTerm;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = SpanNear::new()
+            .clause(SpanTerm::new().field("text_entry").value("to"))
+            .clause(SpanTerm::new().field("text_entry").value("be"))
+            .slop(1_u64)
+            .in_order(true);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "clauses": [
+                { "span_term": { "text_entry": { "value": "to" } } },
+                { "span_term": { "text_entry": { "value": "be" } } }
+            ],
+            "slop": 1,
+            "in_order": true
+        });
+
+        assert_eq!(json, expected);
+    }
+}
@@ -0,0 +1,102 @@
+use super::Span;
+use serde::Serialize;
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct SpanNot {
+    include: Option<Span>,
+    exclude: Option<Span>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pre: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    post: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dist: Option<u64>,
+}
+
+impl SpanNot {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn include<T: Into<Span>>(self, include: T) -> Self {
+        Self {
+            include: Some(include.into()),
+            ..self
+        }
+    }
+
+    pub fn exclude<T: Into<Span>>(self, exclude: T) -> Self {
+        Self {
+            exclude: Some(exclude.into()),
+            ..self
+        }
+    }
+
+    /// Sets pre field, the number of tokens before the include span kept clear of exclude.
+    pub fn pre<T: Into<u64>>(self, pre: T) -> Self {
+        Self {
+            pre: Some(pre.into()),
+            ..self
+        }
+    }
+
+    /// Sets post field, the number of tokens after the include span kept clear of exclude.
+    pub fn post<T: Into<u64>>(self, post: T) -> Self {
+        Self {
+            post: Some(post.into()),
+            ..self
+        }
+    }
+
+    /// Sets dist field, equivalent to setting both pre and post.
+    pub fn dist<T: Into<u64>>(self, dist: T) -> Self {
+        Self {
+            dist: Some(dist.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::span::{SpanNear, SpanTerm};
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = SpanNot::new()
+            .include(
+                SpanNear::new()
+                    .clause(SpanTerm::new().field("text_entry").value("good"))
+                    .clause(SpanTerm::new().field("text_entry").value("night"))
+                    .slop(0_u64),
+            )
+            .exclude(SpanTerm::new().field("text_entry").value("sweet"))
+            .pre(1_u64)
+            .post(2_u64);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "include": {
+                "span_near": {
+                    "clauses": [
+                        { "span_term": { "text_entry": { "value": "good" } } },
+                        { "span_term": { "text_entry": { "value": "night" } } }
+                    ],
+                    "slop": 0
+                }
+            },
+            "exclude": {
+                "span_term": { "text_entry": { "value": "sweet" } }
+            },
+            "pre": 1,
+            "post": 2
+        });
+
+        assert_eq!(json, expected);
+    }
+}
@@ -0,0 +1,54 @@
+use super::Span;
+use serde::Serialize;
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct SpanOr {
+    clauses: Vec<Span>,
+}
+
+impl SpanOr {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn clauses<C, T>(self, clauses: C) -> Self
+    where
+        C: IntoIterator<Item = T>,
+        T: Into<Span>,
+    {
+        Self {
+            clauses: clauses.into_iter().map(|c| c.into()).collect(),
+        }
+    }
+
+    pub fn clause<T: Into<Span>>(self, clause: T) -> Self {
+        let mut clauses = self.clauses;
+        clauses.push(clause.into());
+        Self { clauses }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::span::SpanTerm;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = SpanOr::new().clauses(vec![
+            SpanTerm::new().field("text_entry").value("lord"),
+            SpanTerm::new().field("text_entry").value("king"),
+        ]);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "clauses": [
+                { "span_term": { "text_entry": { "value": "lord" } } },
+                { "span_term": { "text_entry": { "value": "king" } } }
+            ]
+        });
+
+        assert_eq!(json, expected);
+    }
+}
@@ -0,0 +1,77 @@
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::Value;
+
+#[derive(Debug, Default, Clone)]
+pub struct SpanTerm {
+    field: Option<String>,
+    value: SpanTermValues,
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize)]
+struct SpanTermValues {
+    value: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    boost: Option<f64>,
+}
+
+impl SpanTerm {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field<T: Into<String>>(self, field: T) -> Self {
+        Self {
+            field: Some(field.into()),
+            ..self
+        }
+    }
+
+    pub fn value<T: Into<Value>>(self, val: T) -> Self {
+        let value = SpanTermValues {
+            value: Some(val.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn boost<T: Into<f64>>(self, boost: T) -> Self {
+        let value = SpanTermValues {
+            boost: Some(boost.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+}
+
+impl Serialize for SpanTerm {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(1))?;
+        state.serialize_entry(self.field.as_deref().unwrap_or_default(), &self.value)?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = SpanTerm::new().field("text_entry").value("hamlet").boost(2);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "text_entry": {
+                "value": "hamlet",
+                "boost": 2.0
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+}
@@ -0,0 +1,68 @@
+use super::Span;
+use serde::Serialize;
+
+/// Matches spans of little which are enclosed in a span of big.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct SpanWithin {
+    big: Option<Span>,
+    little: Option<Span>,
+}
+
+impl SpanWithin {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn big<T: Into<Span>>(self, big: T) -> Self {
+        Self {
+            big: Some(big.into()),
+            ..self
+        }
+    }
+
+    pub fn little<T: Into<Span>>(self, little: T) -> Self {
+        Self {
+            little: Some(little.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::span::{SpanNear, SpanTerm};
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = SpanWithin::new()
+            .big(
+                SpanNear::new()
+                    .clause(SpanTerm::new().field("clause").value("lessee"))
+                    .clause(SpanTerm::new().field("clause").value("premises"))
+                    .slop(10_u64)
+                    .in_order(false),
+            )
+            .little(SpanTerm::new().field("clause").value("repair"));
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "big": {
+                "span_near": {
+                    "clauses": [
+                        { "span_term": { "clause": { "value": "lessee" } } },
+                        { "span_term": { "clause": { "value": "premises" } } }
+                    ],
+                    "slop": 10,
+                    "in_order": false
+                }
+            },
+            "little": {
+                "span_term": { "clause": { "value": "repair" } }
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+}
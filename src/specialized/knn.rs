@@ -0,0 +1,212 @@
+use crate::{options::VectorLimit, QueryClause};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+
+/// Vector query of the k-NN plugin.
+#[derive(Debug, Default, Clone)]
+pub struct Knn {
+    field: Option<String>,
+    value: KnnValues,
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize)]
+struct KnnValues {
+    vector: Vec<f32>,
+
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    limit: Option<VectorLimit>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    method_parameters: Option<MethodParameters>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    filter: Option<QueryClause>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    boost: Option<f64>,
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize)]
+struct MethodParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ef_search: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    nprobes: Option<u64>,
+}
+
+impl Knn {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field<T: Into<String>>(self, field: T) -> Self {
+        Self {
+            field: Some(field.into()),
+            ..self
+        }
+    }
+
+    pub fn vector<T: Into<Vec<f32>>>(self, vector: T) -> Self {
+        let value = KnnValues {
+            vector: vector.into(),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    /// Sets k field, the number of neighbors to return.
+    pub fn k<T: Into<u64>>(self, k: T) -> Self {
+        let value = KnnValues {
+            limit: Some(VectorLimit::K(k.into())),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    /// Sets min_score field for a radial search instead of k.
+    pub fn min_score<T: Into<f64>>(self, min_score: T) -> Self {
+        let value = KnnValues {
+            limit: Some(VectorLimit::MinScore(min_score.into())),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    /// Sets max_distance field for a radial search instead of k.
+    pub fn max_distance<T: Into<f64>>(self, max_distance: T) -> Self {
+        let value = KnnValues {
+            limit: Some(VectorLimit::MaxDistance(max_distance.into())),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    /// Sets ef_search of method_parameters field, used by HNSW indexes.
+    pub fn ef_search<T: Into<u64>>(self, ef_search: T) -> Self {
+        let method_parameters = MethodParameters {
+            ef_search: Some(ef_search.into()),
+            ..self.value.method_parameters.unwrap_or_default()
+        };
+        let value = KnnValues {
+            method_parameters: Some(method_parameters),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    /// Sets nprobes of method_parameters field, used by IVF indexes.
+    pub fn nprobes<T: Into<u64>>(self, nprobes: T) -> Self {
+        let method_parameters = MethodParameters {
+            nprobes: Some(nprobes.into()),
+            ..self.value.method_parameters.unwrap_or_default()
+        };
+        let value = KnnValues {
+            method_parameters: Some(method_parameters),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn filter<T: Into<QueryClause>>(self, filter: T) -> Self {
+        let value = KnnValues {
+            filter: Some(filter.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn boost<T: Into<f64>>(self, boost: T) -> Self {
+        let value = KnnValues {
+            boost: Some(boost.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+}
+
+impl Serialize for Knn {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(1))?;
+        state.serialize_entry(self.field.as_deref().unwrap_or_default(), &self.value)?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::term_level::Term;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = Knn::new()
+            .field("embedding")
+            .vector([0.5, 1.5, -2.0].as_slice())
+            .k(10_u64)
+            .ef_search(100_u64)
+            .filter(Term::new().field("category").value("books"));
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "embedding": {
+                "vector": [0.5, 1.5, -2.0],
+                "k": 10,
+                "method_parameters": {
+                    "ef_search": 100
+                },
+                "filter": {
+                    "term": { "category": { "value": "books" } }
+                }
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_radial_search_to_json() {
+        let query = Knn::new()
+            .field("embedding")
+            .vector(vec![0.25, 0.75])
+            .max_distance(2)
+            .nprobes(8_u64);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "embedding": {
+                "vector": [0.25, 0.75],
+                "max_distance": 2.0,
+                "method_parameters": {
+                    "nprobes": 8
+                }
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_keeps_only_the_last_limit() {
+        let query = Knn::new()
+            .field("embedding")
+            .vector(vec![0.25, 0.75])
+            .k(3_u64)
+            .min_score(0.5);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "embedding": {
+                "vector": [0.25, 0.75],
+                "min_score": 0.5
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+}
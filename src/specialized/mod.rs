@@ -0,0 +1,48 @@
+use serde::Serialize;
+
+mod knn;
+mod more_like_this;
+mod percolate;
+mod script;
+mod script_score;
+mod wrapper;
+
+pub use knn::Knn;
+pub use more_like_this::{Like, MoreLikeThis};
+pub use percolate::{Percolate, Percolator};
+pub use script::ScriptQuery;
+pub use script_score::ScriptScore;
+pub use wrapper::Wrapper;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Specialized {
+    #[serde(rename = "script")]
+    ScriptQuery(Box<ScriptQuery>),
+    ScriptScore(Box<ScriptScore>),
+    MoreLikeThis(Box<MoreLikeThis>),
+    Percolate(Box<Percolate>),
+    Knn(Box<Knn>),
+    Wrapper(Box<Wrapper>),
+}
+
+macro_rules! from_types {
+    ($($ty:ident),*) => {
+        $(
+            impl From<$ty> for Specialized {
+                fn from(val: $ty) -> Self {
+                    Self::$ty(Box::new(val.into()))
+                }
+            }
+        )*
+    }
+}
+
+from_types! {
+    ScriptQuery,
+    ScriptScore,
+    MoreLikeThis,
+    Percolate,
+    Knn,
+    Wrapper
+}
@@ -0,0 +1,305 @@
+use crate::options::MinimumShouldMatch;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::Value;
+
+#[derive(Debug, Default, Clone, serde::Serialize)]
+pub struct MoreLikeThis {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fields: Vec<String>,
+
+    like: Vec<Like>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    unlike: Vec<Like>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_term_freq: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_query_terms: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_doc_freq: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_doc_freq: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_word_length: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_word_length: Option<u64>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    stop_words: Vec<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    analyzer: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    minimum_should_match: Option<MinimumShouldMatch>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    boost_terms: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    include: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    boost: Option<f64>,
+}
+
+impl MoreLikeThis {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn fields<F, T>(self, fields: F) -> Self
+    where
+        F: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        Self {
+            fields: fields.into_iter().map(|f| f.into()).collect(),
+            ..self
+        }
+    }
+
+    pub fn field<T: Into<String>>(self, field: T) -> Self {
+        let mut fields = self.fields;
+        fields.push(field.into());
+        Self { fields, ..self }
+    }
+
+    /// Adds a text or document to find similar documents to.
+    pub fn like<T: Into<Like>>(self, like: T) -> Self {
+        let mut likes = self.like;
+        likes.push(like.into());
+        Self {
+            like: likes,
+            ..self
+        }
+    }
+
+    /// Adds a text or document whose terms are excluded from the query.
+    pub fn unlike<T: Into<Like>>(self, unlike: T) -> Self {
+        let mut unlikes = self.unlike;
+        unlikes.push(unlike.into());
+        Self {
+            unlike: unlikes,
+            ..self
+        }
+    }
+
+    pub fn min_term_freq<T: Into<u64>>(self, min_term_freq: T) -> Self {
+        Self {
+            min_term_freq: Some(min_term_freq.into()),
+            ..self
+        }
+    }
+
+    pub fn max_query_terms<T: Into<u64>>(self, max_query_terms: T) -> Self {
+        Self {
+            max_query_terms: Some(max_query_terms.into()),
+            ..self
+        }
+    }
+
+    pub fn min_doc_freq<T: Into<u64>>(self, min_doc_freq: T) -> Self {
+        Self {
+            min_doc_freq: Some(min_doc_freq.into()),
+            ..self
+        }
+    }
+
+    pub fn max_doc_freq<T: Into<u64>>(self, max_doc_freq: T) -> Self {
+        Self {
+            max_doc_freq: Some(max_doc_freq.into()),
+            ..self
+        }
+    }
+
+    pub fn min_word_length<T: Into<u64>>(self, min_word_length: T) -> Self {
+        Self {
+            min_word_length: Some(min_word_length.into()),
+            ..self
+        }
+    }
+
+    pub fn max_word_length<T: Into<u64>>(self, max_word_length: T) -> Self {
+        Self {
+            max_word_length: Some(max_word_length.into()),
+            ..self
+        }
+    }
+
+    pub fn stop_words<W, T>(self, stop_words: W) -> Self
+    where
+        W: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        Self {
+            stop_words: stop_words.into_iter().map(|w| w.into()).collect(),
+            ..self
+        }
+    }
+
+    pub fn analyzer<T: Into<String>>(self, analyzer: T) -> Self {
+        Self {
+            analyzer: Some(analyzer.into()),
+            ..self
+        }
+    }
+
+    /// Sets minimum_should_match field, either a number of terms or a percentage like `30%`.
+    pub fn minimum_should_match<T: Into<MinimumShouldMatch>>(
+        self,
+        minimum_should_match: T,
+    ) -> Self {
+        Self {
+            minimum_should_match: Some(minimum_should_match.into()),
+            ..self
+        }
+    }
+
+    pub fn boost_terms<T: Into<f64>>(self, boost_terms: T) -> Self {
+        Self {
+            boost_terms: Some(boost_terms.into()),
+            ..self
+        }
+    }
+
+    pub fn include(self, include: bool) -> Self {
+        Self {
+            include: Some(include),
+            ..self
+        }
+    }
+
+    pub fn boost<T: Into<f64>>(self, boost: T) -> Self {
+        Self {
+            boost: Some(boost.into()),
+            ..self
+        }
+    }
+}
+
+/// An entry of the like or unlike field of a more_like_this query.
+#[derive(Debug, Clone)]
+pub enum Like {
+    /// Free text.
+    Text(String),
+    /// A document stored in an index.
+    Document { index: String, id: String },
+    /// A document which doesn't exist in any index.
+    Artificial { index: String, doc: Value },
+}
+
+impl Like {
+    pub fn text<T: Into<String>>(text: T) -> Self {
+        Self::Text(text.into())
+    }
+
+    pub fn document<T: Into<String>, U: Into<String>>(index: T, id: U) -> Self {
+        Self::Document {
+            index: index.into(),
+            id: id.into(),
+        }
+    }
+
+    pub fn artificial<T: Into<String>, U: Into<Value>>(index: T, doc: U) -> Self {
+        Self::Artificial {
+            index: index.into(),
+            doc: doc.into(),
+        }
+    }
+}
+
+impl From<&str> for Like {
+    fn from(text: &str) -> Self {
+        Self::text(text)
+    }
+}
+
+impl From<String> for Like {
+    fn from(text: String) -> Self {
+        Self::text(text)
+    }
+}
+
+impl Serialize for Like {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Like::Text(text) => serializer.serialize_str(text),
+            Like::Document { index, id } => {
+                let mut state = serializer.serialize_map(Some(2))?;
+                state.serialize_entry("_index", index)?;
+                state.serialize_entry("_id", id)?;
+                state.end()
+            }
+            Like::Artificial { index, doc } => {
+                let mut state = serializer.serialize_map(Some(2))?;
+                state.serialize_entry("_index", index)?;
+                state.serialize_entry("doc", doc)?;
+                state.end()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = MoreLikeThis::new()
+            .fields(vec!["title", "description"])
+            .like("Once upon a time")
+            .like(Like::document("articles", "1"))
+            .like(Like::artificial(
+                "articles",
+                serde_json::json!({ "title": "Tokio internals" }),
+            ))
+            .unlike(Like::document("articles", "2"))
+            .min_term_freq(1_u64)
+            .max_query_terms(12_u64)
+            .min_doc_freq(5_u64)
+            .max_doc_freq(1000_u64)
+            .min_word_length(3_u64)
+            .max_word_length(20_u64)
+            .stop_words(vec!["the", "a"])
+            .minimum_should_match("30%")
+            .boost_terms(1)
+            .include(false);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "fields": ["title", "description"],
+            "like": [
+                "Once upon a time",
+                { "_index": "articles", "_id": "1" },
+                { "_index": "articles", "doc": { "title": "Tokio internals" } }
+            ],
+            "unlike": [
+                { "_index": "articles", "_id": "2" }
+            ],
+            "min_term_freq": 1,
+            "max_query_terms": 12,
+            "min_doc_freq": 5,
+            "max_doc_freq": 1000,
+            "min_word_length": 3,
+            "max_word_length": 20,
+            "stop_words": ["the", "a"],
+            "minimum_should_match": "30%",
+            "boost_terms": 1.0,
+            "include": false
+        });
+
+        assert_eq!(json, expected);
+    }
+}
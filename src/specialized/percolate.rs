@@ -0,0 +1,264 @@
+use crate::QueryClause;
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::Value;
+
+#[derive(Debug, Default, Clone, serde::Serialize)]
+pub struct Percolate {
+    field: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    document: Option<Value>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    documents: Vec<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    routing: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preference: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+}
+
+impl Percolate {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets field field, the `percolator` field holding the stored queries.
+    pub fn field<T: Into<String>>(self, field: T) -> Self {
+        Self {
+            field: Some(field.into()),
+            ..self
+        }
+    }
+
+    pub fn document<T: Into<Value>>(self, document: T) -> Self {
+        Self {
+            document: Some(document.into()),
+            ..self
+        }
+    }
+
+    pub fn documents<D, T>(self, documents: D) -> Self
+    where
+        D: IntoIterator<Item = T>,
+        T: Into<Value>,
+    {
+        Self {
+            documents: documents.into_iter().map(|d| d.into()).collect(),
+            ..self
+        }
+    }
+
+    /// Sets index field of an indexed document to percolate.
+    pub fn index<T: Into<String>>(self, index: T) -> Self {
+        Self {
+            index: Some(index.into()),
+            ..self
+        }
+    }
+
+    /// Sets id field of an indexed document to percolate.
+    pub fn id<T: Into<String>>(self, id: T) -> Self {
+        Self {
+            id: Some(id.into()),
+            ..self
+        }
+    }
+
+    pub fn routing<T: Into<String>>(self, routing: T) -> Self {
+        Self {
+            routing: Some(routing.into()),
+            ..self
+        }
+    }
+
+    pub fn preference<T: Into<String>>(self, preference: T) -> Self {
+        Self {
+            preference: Some(preference.into()),
+            ..self
+        }
+    }
+
+    pub fn version<T: Into<u64>>(self, version: T) -> Self {
+        Self {
+            version: Some(version.into()),
+            ..self
+        }
+    }
+
+    /// Sets name field, used to tell the results of several percolate queries apart.
+    pub fn name<T: Into<String>>(self, name: T) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..self
+        }
+    }
+}
+
+/// A query to store in a `percolator` field, serialized as `{ field: query }`.
+#[derive(Debug, Default, Clone)]
+pub struct Percolator {
+    field: Option<String>,
+    query: Option<QueryClause>,
+}
+
+impl Percolator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the name of the `percolator` field. Defaults to `query`.
+    pub fn field<T: Into<String>>(self, field: T) -> Self {
+        Self {
+            field: Some(field.into()),
+            ..self
+        }
+    }
+
+    pub fn query<T: Into<QueryClause>>(self, query: T) -> Self {
+        Self {
+            query: Some(query.into()),
+            ..self
+        }
+    }
+
+    /// Returns the mapping of the `percolator` field.
+    pub fn mapping(&self) -> Value {
+        serde_json::json!({
+            "properties": {
+                self.field.as_deref().unwrap_or("query"): {
+                    "type": "percolator"
+                }
+            }
+        })
+    }
+}
+
+impl Serialize for Percolator {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(1))?;
+        state.serialize_entry(self.field.as_deref().unwrap_or("query"), &self.query)?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compound::Bool;
+    use crate::full_text::Match;
+    use crate::term_level::Range;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = Percolate::new()
+            .field("search")
+            .document(serde_json::json!({ "title": "Rust 2.0 released", "price": 0 }))
+            .name("new_release");
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "field": "search",
+            "document": {
+                "title": "Rust 2.0 released",
+                "price": 0
+            },
+            "name": "new_release"
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_documents_to_json() {
+        let query = Percolate::new().field("search").documents(vec![
+            serde_json::json!({ "title": "first" }),
+            serde_json::json!({ "title": "second" }),
+        ]);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "field": "search",
+            "documents": [
+                { "title": "first" },
+                { "title": "second" }
+            ]
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_indexed_document_to_json() {
+        let query = Percolate::new()
+            .field("search")
+            .index("articles")
+            .id("1")
+            .routing("eu")
+            .version(3_u64);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "field": "search",
+            "index": "articles",
+            "id": "1",
+            "routing": "eu",
+            "version": 3
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_stored_query_to_json() {
+        let stored = Percolator::new().field("search").query(
+            Bool::new()
+                .must(Match::new().field("title").value("rust"))
+                .filter(Range::new().field("price").lte(10)),
+        );
+
+        let json = serde_json::to_value(&stored).unwrap();
+
+        let expected = serde_json::json!({
+            "search": {
+                "bool": {
+                    "must": [
+                        { "match": { "title": { "query": "rust" } } }
+                    ],
+                    "filter": [
+                        { "range": { "price": { "lte": 10 } } }
+                    ]
+                }
+            }
+        });
+
+        assert_eq!(json, expected);
+
+        let mapping = serde_json::json!({
+            "properties": {
+                "search": { "type": "percolator" }
+            }
+        });
+
+        assert_eq!(stored.mapping(), mapping);
+    }
+}
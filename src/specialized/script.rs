@@ -0,0 +1,60 @@
+use crate::options::Script;
+use serde::Serialize;
+
+/// Filters documents with a script returning a boolean.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct ScriptQuery {
+    script: Option<Script>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    boost: Option<f64>,
+}
+
+impl ScriptQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn script<T: Into<Script>>(self, script: T) -> Self {
+        Self {
+            script: Some(script.into()),
+            ..self
+        }
+    }
+
+    pub fn boost<T: Into<f64>>(self, boost: T) -> Self {
+        Self {
+            boost: Some(boost.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = ScriptQuery::new().script(
+            Script::new()
+                .source("doc['line_id'].value > params.min_line_id")
+                .lang("painless")
+                .param("min_line_id", 100),
+        );
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "script": {
+                "source": "doc['line_id'].value > params.min_line_id",
+                "lang": "painless",
+                "params": {
+                    "min_line_id": 100
+                }
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+}
@@ -0,0 +1,80 @@
+use crate::options::Script;
+use crate::QueryClause;
+use serde::Serialize;
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct ScriptScore {
+    query: Option<QueryClause>,
+    script: Option<Script>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_score: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    boost: Option<f64>,
+}
+
+impl ScriptScore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn query<T: Into<QueryClause>>(self, query: T) -> Self {
+        Self {
+            query: Some(query.into()),
+            ..self
+        }
+    }
+
+    pub fn script<T: Into<Script>>(self, script: T) -> Self {
+        Self {
+            script: Some(script.into()),
+            ..self
+        }
+    }
+
+    pub fn min_score<T: Into<f64>>(self, min_score: T) -> Self {
+        Self {
+            min_score: Some(min_score.into()),
+            ..self
+        }
+    }
+
+    pub fn boost<T: Into<f64>>(self, boost: T) -> Self {
+        Self {
+            boost: Some(boost.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::full_text::Match;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = ScriptScore::new()
+            .query(Match::new().field("text_entry").value("love"))
+            .script(Script::new().id("likes-score").param("factor", 2))
+            .min_score(1.5);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "query": {
+                "match": { "text_entry": { "query": "love" } }
+            },
+            "script": {
+                "id": "likes-score",
+                "params": {
+                    "factor": 2
+                }
+            },
+            "min_score": 1.5
+        });
+
+        assert_eq!(json, expected);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::QueryClause;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::ser::{Error, Serialize, SerializeMap, Serializer};
+use serde_json::Value;
+
+/// Wraps a query as a base64-encoded JSON string. The encoding happens at serialization time.
+#[derive(Debug, Default, Clone)]
+pub struct Wrapper {
+    query: Option<Wrapped>,
+}
+
+#[derive(Debug, Clone)]
+enum Wrapped {
+    Clause(QueryClause),
+    Json(Value),
+}
+
+impl Wrapper {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn query<T: Into<QueryClause>>(self, query: T) -> Self {
+        Self {
+            query: Some(Wrapped::Clause(query.into())),
+        }
+    }
+
+    /// Sets an arbitrary JSON query to wrap.
+    pub fn json<T: Into<Value>>(self, query: T) -> Self {
+        Self {
+            query: Some(Wrapped::Json(query.into())),
+        }
+    }
+}
+
+impl Serialize for Wrapper {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let encoded = match self.query.as_ref() {
+            Some(Wrapped::Clause(clause)) => Some(serde_json::to_vec(clause)),
+            Some(Wrapped::Json(value)) => Some(serde_json::to_vec(value)),
+            None => None,
+        }
+        .transpose()
+        .map_err(S::Error::custom)?
+        .map(|json| STANDARD.encode(json));
+
+        let mut state = serializer.serialize_map(Some(1))?;
+        state.serialize_entry("query", &encoded)?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::term_level::Term;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = Wrapper::new().query(Term::new().field("user").value("kimchy"));
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "query": "eyJ0ZXJtIjp7InVzZXIiOnsidmFsdWUiOiJraW1jaHkifX19"
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_json_value_to_json() {
+        let query = Wrapper::new().json(serde_json::json!({ "term": { "user": "kimchy" } }));
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "query": "eyJ0ZXJtIjp7InVzZXIiOiJraW1jaHkifX0="
+        });
+
+        assert_eq!(json, expected);
+    }
+}
@@ -0,0 +1,147 @@
+use crate::geo::{Distance, GeoPoint};
+use serde::Serialize;
+
+/// Boosts documents by their proximity to an origin date or location.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct DistanceFeature {
+    field: Option<String>,
+
+    origin: Option<FeatureOrigin>,
+
+    pivot: Option<FeaturePivot>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    boost: Option<f64>,
+}
+
+/// The origin of a distance_feature query.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum FeatureOrigin {
+    /// A date or date math expression such as `now`.
+    Date(String),
+    Geo(GeoPoint),
+}
+
+impl From<&str> for FeatureOrigin {
+    fn from(date: &str) -> Self {
+        Self::Date(date.into())
+    }
+}
+
+impl From<String> for FeatureOrigin {
+    fn from(date: String) -> Self {
+        Self::Date(date)
+    }
+}
+
+impl From<GeoPoint> for FeatureOrigin {
+    fn from(point: GeoPoint) -> Self {
+        Self::Geo(point)
+    }
+}
+
+/// The distance from the origin at which the score is half of the boost.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum FeaturePivot {
+    /// A time unit such as `7d`.
+    Time(String),
+    Distance(Distance),
+}
+
+impl From<&str> for FeaturePivot {
+    fn from(time: &str) -> Self {
+        Self::Time(time.into())
+    }
+}
+
+impl From<String> for FeaturePivot {
+    fn from(time: String) -> Self {
+        Self::Time(time)
+    }
+}
+
+impl From<Distance> for FeaturePivot {
+    fn from(distance: Distance) -> Self {
+        Self::Distance(distance)
+    }
+}
+
+impl DistanceFeature {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field<T: Into<String>>(self, field: T) -> Self {
+        Self {
+            field: Some(field.into()),
+            ..self
+        }
+    }
+
+    pub fn origin<T: Into<FeatureOrigin>>(self, origin: T) -> Self {
+        Self {
+            origin: Some(origin.into()),
+            ..self
+        }
+    }
+
+    pub fn pivot<T: Into<FeaturePivot>>(self, pivot: T) -> Self {
+        Self {
+            pivot: Some(pivot.into()),
+            ..self
+        }
+    }
+
+    pub fn boost<T: Into<f64>>(self, boost: T) -> Self {
+        Self {
+            boost: Some(boost.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::DistanceUnit;
+
+    #[test]
+    fn it_serializes_date_to_json() {
+        let query = DistanceFeature::new()
+            .field("release_date")
+            .origin("now")
+            .pivot("7d")
+            .boost(2);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "field": "release_date",
+            "origin": "now",
+            "pivot": "7d",
+            "boost": 2.0
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_geo_to_json() {
+        let query = DistanceFeature::new()
+            .field("location")
+            .origin(GeoPoint::lat_lon(40.71, 74.0))
+            .pivot(Distance::new(1, DistanceUnit::Kilometers));
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "field": "location",
+            "origin": { "lat": 40.71, "lon": 74.0 },
+            "pivot": "1km"
+        });
+
+        assert_eq!(json, expected);
+    }
+}
@@ -1,24 +1,30 @@
 use serde::Serialize;
 
+mod distance_feature;
 mod exists;
 mod fuzzy;
 mod ids;
 mod prefix;
 mod range;
+mod rank_feature;
 mod regexp;
 mod term;
 mod terms;
+mod terms_lookup;
 mod terms_set;
 mod wildcard;
 
+pub use distance_feature::{DistanceFeature, FeatureOrigin, FeaturePivot};
 pub use exists::Exists;
 pub use fuzzy::Fuzzy;
 pub use ids::Ids;
 pub use prefix::Prefix;
 pub use range::Range;
+pub use rank_feature::RankFeature;
 pub use regexp::Regexp;
 pub use term::Term;
 pub use terms::Terms;
+pub use terms_lookup::TermsLookup;
 pub use terms_set::TermsSet;
 pub use wildcard::Wildcard;
 
@@ -35,6 +41,8 @@ pub enum TermLevel {
     Fuzzy(Fuzzy),
     Wildcard(Wildcard),
     Regexp(Regexp),
+    RankFeature(RankFeature),
+    DistanceFeature(DistanceFeature),
 }
 
 macro_rules! from_term_types {
@@ -59,5 +67,7 @@ from_term_types! {
     Exists,
     Fuzzy,
     Wildcard,
-    Regexp
+    Regexp,
+    RankFeature,
+    DistanceFeature
 }
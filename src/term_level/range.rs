@@ -1,3 +1,4 @@
+use crate::options::RangeRelation;
 use serde::ser::{Serialize, SerializeMap, Serializer};
 use serde_json::Value;
 
@@ -20,6 +21,18 @@ struct RangeValues {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     lt: Option<Value>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_zone: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    relation: Option<RangeRelation>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    boost: Option<f64>,
 }
 
 impl Range {
@@ -33,6 +46,41 @@ impl Range {
             ..self
         }
     }
+
+    /// Sets format field, the date format used to parse the bounds.
+    pub fn format<T: Into<String>>(self, format: T) -> Self {
+        let value = RangeValues {
+            format: Some(format.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    /// Sets time_zone field, a UTC offset like `+01:00` or a time zone id like `Asia/Tokyo`.
+    pub fn time_zone<T: Into<String>>(self, time_zone: T) -> Self {
+        let value = RangeValues {
+            time_zone: Some(time_zone.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    /// Sets relation field, how the query matches the values of range fields.
+    pub fn relation<T: Into<RangeRelation>>(self, relation: T) -> Self {
+        let value = RangeValues {
+            relation: Some(relation.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
+
+    pub fn boost<T: Into<f64>>(self, boost: T) -> Self {
+        let value = RangeValues {
+            boost: Some(boost.into()),
+            ..self.value
+        };
+        Self { value, ..self }
+    }
 }
 
 macro_rules! setter {
@@ -67,6 +115,7 @@ impl Serialize for Range {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::options::{DateMath, DateMathUnit};
 
     #[test]
     fn it_serializes_to_json() {
@@ -83,4 +132,35 @@ mod tests {
 
         assert_eq!(json, expected);
     }
+
+    #[test]
+    fn it_serializes_date_range_to_json() {
+        let range = Range::new()
+            .field("created_at")
+            .gte(
+                DateMath::now()
+                    .subtract(1, DateMathUnit::Days)
+                    .round(DateMathUnit::Days),
+            )
+            .lt(DateMath::date("2024-01-01").add(1, DateMathUnit::Months))
+            .format("yyyy-MM-dd")
+            .time_zone("+01:00")
+            .relation(RangeRelation::Within)
+            .boost(2);
+
+        let json = serde_json::to_value(range).unwrap();
+
+        let expected = serde_json::json!({
+            "created_at": {
+                "gte": "now-1d/d",
+                "lt": "2024-01-01||+1M",
+                "format": "yyyy-MM-dd",
+                "time_zone": "+01:00",
+                "relation": "WITHIN",
+                "boost": 2.0
+            },
+        });
+
+        assert_eq!(json, expected);
+    }
 }
@@ -0,0 +1,162 @@
+use serde::Serialize;
+
+/// Boosts documents by the value of a rank_feature field.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct RankFeature {
+    field: Option<String>,
+
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    function: Option<Function>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    boost: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Function {
+    Saturation {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pivot: Option<f64>,
+    },
+    Log {
+        scaling_factor: f64,
+    },
+    Sigmoid {
+        pivot: f64,
+        exponent: f64,
+    },
+    Linear {},
+}
+
+impl RankFeature {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field<T: Into<String>>(self, field: T) -> Self {
+        Self {
+            field: Some(field.into()),
+            ..self
+        }
+    }
+
+    /// Uses the saturation function with the pivot computed by OpenSearch.
+    pub fn saturation(self) -> Self {
+        Self {
+            function: Some(Function::Saturation { pivot: None }),
+            ..self
+        }
+    }
+
+    /// Uses the saturation function with the given pivot.
+    pub fn saturation_pivot<T: Into<f64>>(self, pivot: T) -> Self {
+        Self {
+            function: Some(Function::Saturation {
+                pivot: Some(pivot.into()),
+            }),
+            ..self
+        }
+    }
+
+    pub fn log<T: Into<f64>>(self, scaling_factor: T) -> Self {
+        Self {
+            function: Some(Function::Log {
+                scaling_factor: scaling_factor.into(),
+            }),
+            ..self
+        }
+    }
+
+    pub fn sigmoid<T: Into<f64>, U: Into<f64>>(self, pivot: T, exponent: U) -> Self {
+        Self {
+            function: Some(Function::Sigmoid {
+                pivot: pivot.into(),
+                exponent: exponent.into(),
+            }),
+            ..self
+        }
+    }
+
+    pub fn linear(self) -> Self {
+        Self {
+            function: Some(Function::Linear {}),
+            ..self
+        }
+    }
+
+    pub fn boost<T: Into<f64>>(self, boost: T) -> Self {
+        Self {
+            boost: Some(boost.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let query = RankFeature::new().field("pagerank").boost(2);
+
+        let json = serde_json::to_value(query).unwrap();
+
+        let expected = serde_json::json!({
+            "field": "pagerank",
+            "boost": 2.0
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_saturation_to_json() {
+        let json = serde_json::to_value(RankFeature::new().field("pagerank").saturation()).unwrap();
+        let expected = serde_json::json!({
+            "field": "pagerank",
+            "saturation": {}
+        });
+        assert_eq!(json, expected);
+
+        let json =
+            serde_json::to_value(RankFeature::new().field("pagerank").saturation_pivot(8)).unwrap();
+        let expected = serde_json::json!({
+            "field": "pagerank",
+            "saturation": { "pivot": 8.0 }
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_log_to_json() {
+        let json = serde_json::to_value(RankFeature::new().field("pagerank").log(4)).unwrap();
+        let expected = serde_json::json!({
+            "field": "pagerank",
+            "log": { "scaling_factor": 4.0 }
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_sigmoid_to_json() {
+        let json =
+            serde_json::to_value(RankFeature::new().field("pagerank").sigmoid(7, 0.6)).unwrap();
+        let expected = serde_json::json!({
+            "field": "pagerank",
+            "sigmoid": { "pivot": 7.0, "exponent": 0.6 }
+        });
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_linear_to_json() {
+        let json = serde_json::to_value(RankFeature::new().field("pagerank").linear()).unwrap();
+        let expected = serde_json::json!({
+            "field": "pagerank",
+            "linear": {}
+        });
+        assert_eq!(json, expected);
+    }
+}
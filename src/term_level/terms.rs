@@ -1,10 +1,26 @@
-use serde::ser::{Serialize, SerializeMap, Serializer};
+use super::TermsLookup;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use roaring::RoaringBitmap;
+use serde::ser::{Error, Serialize, SerializeMap, Serializer};
 use serde_json::Value;
 
 #[derive(Debug, Default, Clone)]
 pub struct Terms {
     field: Option<String>,
-    values: Vec<Value>,
+    source: TermsSource,
+}
+
+#[derive(Debug, Clone)]
+enum TermsSource {
+    Values(Vec<Value>),
+    Lookup(TermsLookup),
+    Bitmap(RoaringBitmap),
+}
+
+impl Default for TermsSource {
+    fn default() -> Self {
+        Self::Values(Vec::new())
+    }
 }
 
 impl Terms {
@@ -25,16 +41,38 @@ impl Terms {
         T: Into<Value>,
     {
         Self {
-            values: values.into_iter().map(|v| v.into()).collect(),
+            source: TermsSource::Values(values.into_iter().map(|v| v.into()).collect()),
             ..self
         }
     }
 
     pub fn value<T: Into<Value>>(self, value: T) -> Self {
-        let mut values = self.values;
+        let mut values = match self.source {
+            TermsSource::Values(values) => values,
+            _ => Vec::new(),
+        };
         values.push(value.into());
 
-        Self { values, ..self }
+        Self {
+            source: TermsSource::Values(values),
+            ..self
+        }
+    }
+
+    /// Fetches the terms from a field of a document in another index.
+    pub fn lookup<T: Into<TermsLookup>>(self, lookup: T) -> Self {
+        Self {
+            source: TermsSource::Lookup(lookup.into()),
+            ..self
+        }
+    }
+
+    /// Matches the given ids as a base64-encoded RoaringBitmap with `value_type: bitmap`.
+    pub fn bitmap<I: IntoIterator<Item = u32>>(self, ids: I) -> Self {
+        Self {
+            source: TermsSource::Bitmap(ids.into_iter().collect()),
+            ..self
+        }
     }
 }
 
@@ -43,8 +81,20 @@ impl Serialize for Terms {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_map(Some(1))?;
-        state.serialize_entry(&self.field.as_deref().unwrap_or_default(), &self.values)?;
+        let field = self.field.as_deref().unwrap_or_default();
+        let mut state = serializer.serialize_map(None)?;
+        match &self.source {
+            TermsSource::Values(values) => state.serialize_entry(field, values)?,
+            TermsSource::Lookup(lookup) => state.serialize_entry(field, lookup)?,
+            TermsSource::Bitmap(bitmap) => {
+                let mut bytes = Vec::with_capacity(bitmap.serialized_size());
+                bitmap
+                    .serialize_into(&mut bytes)
+                    .map_err(S::Error::custom)?;
+                state.serialize_entry(field, &STANDARD.encode(bytes))?;
+                state.serialize_entry("value_type", "bitmap")?;
+            }
+        }
         state.end()
     }
 }
@@ -64,4 +114,48 @@ mod tests {
 
         assert_eq!(json, expected);
     }
+
+    #[test]
+    fn it_serializes_lookup_to_json() {
+        let terms = Terms::new()
+            .field("user_id")
+            .lookup(TermsLookup::new().index("users").id("1").path("following"));
+        let json = serde_json::to_value(terms).unwrap();
+
+        let expected = serde_json::json!({
+            "user_id": {
+                "index": "users",
+                "id": "1",
+                "path": "following"
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_serializes_bitmap_to_json() {
+        let terms = Terms::new().field("product_id").bitmap(vec![111, 333]);
+        let json = serde_json::to_value(terms).unwrap();
+
+        let expected = serde_json::json!({
+            "product_id": "OjAAAAEAAAAAAAEAEAAAAG8ATQE=",
+            "value_type": "bitmap"
+        });
+
+        assert_eq!(json, expected);
+    }
+
+    #[test]
+    fn it_encodes_bitmap_ids() {
+        let ids: Vec<u32> = (0..100_000).step_by(3).chain([u32::MAX]).collect();
+        let terms = Terms::new().field("product_id").bitmap(ids.clone());
+        let json = serde_json::to_value(terms).unwrap();
+
+        let encoded = json["product_id"].as_str().unwrap();
+        let bytes = STANDARD.decode(encoded).unwrap();
+        let bitmap = RoaringBitmap::deserialize_from(&bytes[..]).unwrap();
+
+        assert_eq!(bitmap.into_iter().collect::<Vec<u32>>(), ids);
+    }
 }
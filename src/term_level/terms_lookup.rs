@@ -0,0 +1,72 @@
+use serde::Serialize;
+
+/// A reference to the terms stored in a field of a document of another index.
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct TermsLookup {
+    index: Option<String>,
+    id: Option<String>,
+    path: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    routing: Option<String>,
+}
+
+impl TermsLookup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn index<T: Into<String>>(self, index: T) -> Self {
+        Self {
+            index: Some(index.into()),
+            ..self
+        }
+    }
+
+    pub fn id<T: Into<String>>(self, id: T) -> Self {
+        Self {
+            id: Some(id.into()),
+            ..self
+        }
+    }
+
+    /// Sets path field, the field of the document holding the terms.
+    pub fn path<T: Into<String>>(self, path: T) -> Self {
+        Self {
+            path: Some(path.into()),
+            ..self
+        }
+    }
+
+    pub fn routing<T: Into<String>>(self, routing: T) -> Self {
+        Self {
+            routing: Some(routing.into()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_serializes_to_json() {
+        let lookup = TermsLookup::new()
+            .index("users")
+            .id("1")
+            .path("following")
+            .routing("shard-1");
+
+        let json = serde_json::to_value(lookup).unwrap();
+
+        let expected = serde_json::json!({
+            "index": "users",
+            "id": "1",
+            "path": "following",
+            "routing": "shard-1"
+        });
+
+        assert_eq!(json, expected);
+    }
+}
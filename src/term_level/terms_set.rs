@@ -1,3 +1,4 @@
+use crate::options::Script;
 use serde::ser::{Serialize, SerializeMap, Serializer};
 use serde_json::Value;
 
@@ -56,12 +57,10 @@ impl TermsSet {
 
     pub fn minimum_should_match_script<T>(self, script: T) -> Self
     where
-        T: Into<String>,
+        T: Into<Script>,
     {
         let value = TermsSetValues {
-            minimum_should_match_script: Some(MinShouldMatchScript {
-                source: script.into(),
-            }),
+            minimum_should_match_script: Some(script.into()),
             ..self.value
         };
         Self { value, ..self }
@@ -76,12 +75,7 @@ struct TermsSetValues {
     minimum_should_match_field: Option<String>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
-    minimum_should_match_script: Option<MinShouldMatchScript>,
-}
-
-#[derive(Debug, Clone, serde::Serialize)]
-struct MinShouldMatchScript {
-    source: String,
+    minimum_should_match_script: Option<Script>,
 }
 
 impl Serialize for TermsSet {